@@ -0,0 +1,108 @@
+// Throughput of hand evaluation, for each evaluator on the same inputs.
+//
+//     cargo bench --bench evaluator [-- FILTER]
+//
+// Random hands are drawn from a fixed seed so that runs can be compared.
+// The worst cases make every evaluator do all of its work: a full tie for
+// `winning_hands`, and a high card hand with four to a flush and a broken
+// straight, which falls through every category check.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use poker::{
+    parse_cards, winning_hands, Cached, Card, Deck, Evaluator, Exhaustive, Incremental, Rng,
+};
+
+const HANDS: usize = 1000;
+
+fn random_hands(size: usize) -> Vec<Vec<Card>> {
+    let mut rng = Rng::new(46);
+    (0..HANDS)
+        .map(|_| {
+            let mut deck = Deck::shuffled(&mut rng);
+            (0..size).map(|_| deck.deal().unwrap()).collect()
+        })
+        .collect()
+}
+
+fn worst_hands(cards: &str) -> Vec<Vec<Card>> {
+    vec![parse_cards(cards).unwrap(); HANDS]
+}
+
+fn bench_evaluator<E: Evaluator>(
+    c: &mut Criterion,
+    name: &str,
+    mut evaluator: E,
+    inputs: &[(&str, usize, Vec<Vec<Card>>)],
+) {
+    for (input, size, hands) in inputs {
+        let mut group = c.benchmark_group(format!("{size}_card"));
+        group.throughput(Throughput::Elements(hands.len() as u64));
+        group.bench_with_input(BenchmarkId::new(name, input), hands, |b, hands| {
+            b.iter(|| {
+                hands
+                    .iter()
+                    .map(|hand| evaluator.strength(hand).unwrap().1)
+                    .fold(0, u32::max)
+            })
+        });
+        group.finish();
+    }
+}
+
+fn evaluation(c: &mut Criterion) {
+    let inputs = [
+        ("random", 5, random_hands(5)),
+        ("worst", 5, worst_hands("AS KS QS JS 9H")),
+        ("random", 7, random_hands(7)),
+        ("worst", 7, worst_hands("AS KS QS JS 9H 7D 2C")),
+    ];
+    bench_evaluator(c, "exhaustive", Exhaustive, &inputs);
+    bench_evaluator(c, "incremental", Incremental, &inputs);
+    // Every input fits, so after the first pass this is all lookups.
+    bench_evaluator(c, "cached", Cached::new(Exhaustive, HANDS), &inputs);
+}
+
+fn winning(c: &mut Criterion) {
+    // Tables of four five card hands, as strings.
+    let random: Vec<Vec<String>> = random_hands(5)
+        .chunks(4)
+        .map(|table| {
+            table
+                .iter()
+                .map(|hand| {
+                    let cards: Vec<String> = hand.iter().map(Card::to_string).collect();
+                    cards.join(" ")
+                })
+                .collect()
+        })
+        .collect();
+    let tied = vec![
+        vec![
+            "4S 5S 7H 8D JC".to_string(),
+            "4H 5H 7D 8C JS".to_string(),
+            "4D 5D 7C 8S JH".to_string(),
+            "4C 5C 7S 8H JD".to_string(),
+        ];
+        random.len()
+    ];
+
+    let mut group = c.benchmark_group("winning_hands");
+    group.throughput(Throughput::Elements(random.len() as u64));
+    for (input, tables) in [("random", &random), ("tied", &tied)] {
+        group.bench_with_input(BenchmarkId::from_parameter(input), tables, |b, tables| {
+            b.iter(|| {
+                tables
+                    .iter()
+                    .map(|table| {
+                        let hands: Vec<&str> = table.iter().map(String::as_str).collect();
+                        winning_hands(&hands).len()
+                    })
+                    .sum::<usize>()
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, evaluation, winning);
+criterion_main!(benches);
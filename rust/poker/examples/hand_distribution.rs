@@ -0,0 +1,33 @@
+// Print how often each hand comes up, over every deal of five or seven
+// cards.
+//
+//     cargo run --release --example hand_distribution -- [5|7] [CSV_PATH]
+//
+// Writes the same table to CSV_PATH if one is given.
+
+use anyhow::{bail, Context, Result};
+use poker::HandDistribution;
+
+fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let (cards, csv_path) = match &args[..] {
+        [] => ("5", None),
+        [cards] => (cards.as_str(), None),
+        [cards, path] => (cards.as_str(), Some(path)),
+        _ => bail!("usage: hand_distribution [5|7] [CSV_PATH]"),
+    };
+
+    let distribution = match cards {
+        "5" => HandDistribution::five_card(),
+        "7" => HandDistribution::seven_card(),
+        _ => bail!("can only count five or seven card hands, not {cards}"),
+    };
+    print!("{distribution}");
+
+    if let Some(path) = csv_path {
+        std::fs::write(path, distribution.to_csv())
+            .with_context(|| format!("couldn't write {path}"))?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+// Import online hand histories and total up one player's results.
+//
+//     cargo run --example hand_histories -- PLAYER PATH...
+//
+// Each file may hold any number of PokerStars, GGPoker or Winamax hands.
+// Every showdown is re-evaluated, and hands whose pot didn't go where it
+// should have are flagged.
+
+use anyhow::{bail, Context, Result};
+use poker::parse_histories;
+
+fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let [player, paths @ ..] = &args[..] else {
+        bail!("usage: hand_histories PLAYER PATH...");
+    };
+
+    let (mut hands, mut net, mut flagged) = (0, 0, 0);
+    for path in paths {
+        let text =
+            std::fs::read_to_string(path).with_context(|| format!("failed to read {path}"))?;
+        let histories =
+            parse_histories(&text).with_context(|| format!("failed to parse {path}"))?;
+
+        for history in histories {
+            let Some(seat) = history.seats.iter().position(|seat| &seat.player == player) else {
+                continue;
+            };
+            let result = history.net()[seat];
+            hands += 1;
+            net += result;
+
+            print!(
+                "{:?} #{}: {:+.2}",
+                history.site,
+                history.id,
+                result as f64 / 100.0
+            );
+            match history.verify() {
+                Ok(()) => println!(),
+                Err(err) => {
+                    flagged += 1;
+                    println!(" ({err})");
+                }
+            }
+        }
+    }
+
+    println!(
+        "{player}: {hands} hands, {:+.2} net, {flagged} flagged",
+        net as f64 / 100.0
+    );
+    Ok(())
+}
@@ -0,0 +1,198 @@
+// Play no-limit Hold'em in the terminal against rule-based bots.
+//
+//     cargo run --example holdem -- [--players N] [--stack CHIPS] [--seed N]
+//
+// You are the hero in seat 0. On your turn type `f` to fold, `c` to check or
+// call, `r AMOUNT` to bet or raise to AMOUNT, or `q` to leave the table.
+
+use std::io::{self, BufRead, Write};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{bail, Context, Result};
+use poker::{Action, Bot, Card, Deck, Game, Rng, RuleBot};
+
+const SMALL_BLIND: u32 = 5;
+const BIG_BLIND: u32 = 10;
+
+struct Player {
+    name: String,
+    stack: u32,
+    bot: Option<RuleBot>,
+}
+
+fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let (players, stack, seed) = parse_args(&args)?;
+    let mut rng = Rng::new(seed);
+
+    let styles = [(true, true), (false, true), (true, false), (false, false)];
+    let mut table: Vec<Player> = vec![Player {
+        name: "hero".to_string(),
+        stack,
+        bot: None,
+    }];
+    for n in 1..players {
+        let (tight, aggressive) = styles[(n - 1) % styles.len()];
+        let bot = RuleBot::new(tight, aggressive, rng.next_u64());
+        table.push(Player {
+            name: format!("bot {n} ({})", bot.style()),
+            stack,
+            bot: Some(bot),
+        });
+    }
+
+    let mut lines = io::stdin().lock().lines();
+    let mut button = 0;
+    loop {
+        let stacks: Vec<u32> = table.iter().map(|player| player.stack).collect();
+        let mut game = Game::new(
+            &stacks,
+            button,
+            SMALL_BLIND,
+            BIG_BLIND,
+            Deck::shuffled(&mut rng),
+        )?;
+        println!(
+            "\n=== new hand, {} has the button ===",
+            table[game.button()].name
+        );
+        println!("your cards: {}", cards(&game.seats()[0].hole));
+
+        let mut street = game.street();
+        while let Some(seat) = game.to_act() {
+            if game.street() != street {
+                street = game.street();
+                println!(
+                    "--- {street:?}: {} (pot {})",
+                    cards(game.board()),
+                    game.pot()
+                );
+            }
+
+            let action = match &mut table[seat].bot {
+                Some(bot) => bot.act(&game.state(seat)),
+                None => match prompt(&game, &mut lines)? {
+                    Some(action) => action,
+                    None => return Ok(()),
+                },
+            };
+
+            match game.apply(action) {
+                Ok(()) => println!("{} {action}", table[seat].name),
+                Err(err) => println!("{err}, try again"),
+            }
+        }
+
+        for (seat, evaluation) in game.showdown() {
+            println!(
+                "{} shows {}: {}",
+                table[seat].name,
+                cards(&game.seats()[seat].hole),
+                evaluation.description()
+            );
+        }
+        for (seat, &won) in game.payouts().unwrap_or_default().iter().enumerate() {
+            if won > 0 {
+                println!("{} wins {won}", table[seat].name);
+            }
+        }
+
+        for (player, seat) in table.iter_mut().zip(game.seats()) {
+            player.stack = seat.stack;
+        }
+        if table[0].stack == 0 {
+            println!("you're out of chips, thanks for playing");
+            return Ok(());
+        }
+        // The button goes to the next player with chips, found before anyone
+        // busted is taken out and the seats after them move down.
+        let next = (1..=table.len())
+            .map(|i| (button + i) % table.len())
+            .find(|&i| table[i].stack > 0)
+            .expect("you still have chips");
+        button = table[..next]
+            .iter()
+            .filter(|player| player.stack > 0)
+            .count();
+        table.retain(|player| player.stack > 0);
+        if table.len() == 1 {
+            println!("you've won every chip at the table!");
+            return Ok(());
+        }
+    }
+}
+
+fn parse_args(mut args: &[String]) -> Result<(usize, u32, u64)> {
+    let mut players = 2;
+    let mut stack = 1000;
+    let mut seed = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+
+    loop {
+        match args {
+            [] => break,
+            [flag, value, rest @ ..] if flag == "--players" => {
+                players = value.parse().context("--players must be a number")?;
+                args = rest;
+            }
+            [flag, value, rest @ ..] if flag == "--stack" => {
+                stack = value.parse().context("--stack must be a number")?;
+                args = rest;
+            }
+            [flag, value, rest @ ..] if flag == "--seed" => {
+                seed = value.parse().context("--seed must be a number")?;
+                args = rest;
+            }
+            [arg, ..] => bail!("unexpected argument {arg}"),
+        }
+    }
+
+    if !(2..=9).contains(&players) {
+        bail!("--players must be between 2 and 9");
+    }
+    if stack < BIG_BLIND {
+        bail!("--stack must be at least the big blind ({BIG_BLIND})");
+    }
+
+    Ok((players, stack, seed))
+}
+
+fn prompt(
+    game: &Game,
+    lines: &mut impl Iterator<Item = io::Result<String>>,
+) -> Result<Option<Action>> {
+    let to_call = game.to_call(0);
+    loop {
+        print!(
+            "pot {}, stack {}, to call {to_call} > ",
+            game.pot(),
+            game.seats()[0].stack
+        );
+        io::stdout().flush()?;
+
+        let Some(line) = lines.next() else {
+            return Ok(None);
+        };
+        let line = line?;
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let action = match words[..] {
+            ["q"] => return Ok(None),
+            ["f"] => Action::Fold,
+            ["c"] if to_call == 0 => Action::Check,
+            ["c"] => Action::Call,
+            ["r", amount] => match amount.parse() {
+                Ok(to) => Action::Raise(to),
+                Err(_) => continue,
+            },
+            _ => {
+                println!("f = fold, c = check/call, r AMOUNT = raise to AMOUNT, q = quit");
+                continue;
+            }
+        };
+        return Ok(Some(action));
+    }
+}
+
+fn cards(cards: &[Card]) -> String {
+    let cards: Vec<String> = cards.iter().map(Card::to_string).collect();
+    cards.join(" ")
+}
@@ -0,0 +1,34 @@
+// Build the preflop equity chart from scratch.
+//
+//     cargo run --release --example preflop_chart -- [TRIALS] [SEED] [CSV_PATH]
+//
+// Prints the grid against each number of opponents, and writes every number
+// to CSV_PATH (default `preflop.csv`). The same trials and seed always give
+// the same chart.
+
+use anyhow::{Context, Result};
+use poker::PreflopChart;
+
+fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let trials = match args.first() {
+        Some(trials) => trials.parse().context("TRIALS must be a number")?,
+        None => 2000,
+    };
+    let seed = match args.get(1) {
+        Some(seed) => seed.parse().context("SEED must be a number")?,
+        None => 0,
+    };
+    let path = args.get(2).map_or("preflop.csv", String::as_str);
+
+    let chart = PreflopChart::generate(9, trials, seed).context("TRIALS must be at least one")?;
+    for opponents in 1..=9 {
+        println!("equity (%) against {opponents} random hand(s):");
+        println!("{}", chart.ascii_grid(opponents));
+    }
+
+    std::fs::write(path, chart.to_csv()).with_context(|| format!("failed to write {path}"))?;
+    println!("wrote {path}");
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+// Solve heads-up push or fold at one stack depth.
+//
+//     cargo run --release --example push_fold -- [STACK_BB] [ITERATIONS] [TRIALS] [SEED]
+//
+// Estimates every starting hand's all-in equity against every other with
+// TRIALS deals, runs CFR for ITERATIONS, then prints how often (%) the small
+// blind pushes and the big blind calls with each hand.
+
+use anyhow::{Context, Result};
+use poker::{Cfr, HeadsUpEquities, PushFold};
+
+fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let stack = match args.first() {
+        Some(stack) => stack.parse().context("STACK_BB must be a number")?,
+        None => 10.0,
+    };
+    let iterations = match args.get(1) {
+        Some(iterations) => iterations.parse().context("ITERATIONS must be a number")?,
+        None => 500,
+    };
+    let trials = match args.get(2) {
+        Some(trials) => trials.parse().context("TRIALS must be a number")?,
+        None => 500,
+    };
+    let seed = match args.get(3) {
+        Some(seed) => seed.parse().context("SEED must be a number")?,
+        None => 0,
+    };
+
+    let equities =
+        HeadsUpEquities::estimate(trials, seed).context("TRIALS must be at least one")?;
+    let mut cfr = Cfr::new(PushFold::new(stack, equities));
+    cfr.run(iterations);
+    let strategy = cfr.strategy();
+    let game = cfr.game();
+
+    println!(
+        "small blind pushes {:.1}% of hands at {stack}bb:",
+        game.range(&strategy, 0) * 100.0
+    );
+    println!("{}", game.chart(&strategy, 0));
+    println!(
+        "big blind calls {:.1}% of hands:",
+        game.range(&strategy, 1) * 100.0
+    );
+    println!("{}", game.chart(&strategy, 1));
+    println!("exploitability: {:.5}bb", cfr.exploitability());
+
+    Ok(())
+}
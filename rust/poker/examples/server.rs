@@ -0,0 +1,35 @@
+// Host a table on localhost for bots to connect to.
+//
+//     cargo run --example server -- [PORT] [SEATS] [HANDS]
+//
+// Then connect with anything that speaks the line protocol, e.g.
+// `nc localhost 7878` and type `JOIN yourname`.
+
+use anyhow::{Context, Result};
+use poker::{Server, ServerConfig};
+
+fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let number = |i: usize, default: usize| -> Result<usize> {
+        args.get(i)
+            .map_or(Ok(default), |arg| arg.parse())
+            .with_context(|| format!("expected a number, got {:?}", args[i]))
+    };
+
+    let port: u16 = args
+        .first()
+        .map_or(Ok(7878), |arg| arg.parse())
+        .with_context(|| format!("expected a port number, got {:?}", args[0]))?;
+    let config = ServerConfig {
+        seats: number(1, 2)?,
+        hands: number(2, 100)?,
+        ..ServerConfig::default()
+    };
+
+    let server = Server::bind(("127.0.0.1", port), config)?;
+    println!("waiting for players on {}", server.local_addr()?);
+    let stacks = server.run()?;
+    println!("final stacks: {stacks:?}");
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+// Round-robin heads-up matches between the built-in bots.
+//
+//     cargo run --release --example tournament -- [DEALS] [SEED]
+//
+// Every deal is played twice with the bots swapping seats, see `play_match`.
+
+use anyhow::{Context, Result};
+use poker::{play_match, Bot, CallingStation, MatchConfig, RuleBot};
+
+fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let mut config = MatchConfig::default();
+    if let Some(deals) = args.first() {
+        config.deals = deals.parse().context("DEALS must be a number")?;
+    }
+    if let Some(seed) = args.get(1) {
+        config.seed = seed.parse().context("SEED must be a number")?;
+    }
+
+    let lineup = || -> Vec<(String, Box<dyn Bot>)> {
+        let mut bots: Vec<(String, Box<dyn Bot>)> =
+            [(true, true), (false, true), (true, false), (false, false)]
+                .into_iter()
+                .map(|(tight, aggressive)| {
+                    let bot = RuleBot::new(tight, aggressive, config.seed);
+                    (bot.style().to_string(), Box::new(bot) as Box<dyn Bot>)
+                })
+                .collect();
+        bots.push(("calling station".to_string(), Box::new(CallingStation)));
+        bots
+    };
+
+    let mut bots = lineup();
+    let mut opponents = lineup();
+    for (i, (name, bot)) in bots.iter_mut().enumerate() {
+        for (opponent_name, opponent) in opponents.iter_mut().skip(i + 1) {
+            let result = play_match(bot.as_mut(), opponent.as_mut(), &config)?;
+            println!(
+                "{name} vs {opponent_name}: {:+.1} ± {:.1} bb/100 over {} hands",
+                result.bb_per_100, result.ci95, result.hands
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,23 @@
+// Throw arbitrary strings at the hand and card parsers.
+//
+//     cargo +nightly fuzz run parse
+//
+// Hands are separated by `|`, so one input can hold several to compare.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use poker::{evaluate, parse_cards, winning_hands};
+
+fuzz_target!(|data: &str| {
+    let hands: Vec<&str> = data.split('|').collect();
+    let winners = winning_hands(&hands);
+    assert!(winners.len() <= hands.len());
+
+    if let Ok(cards) = parse_cards(data) {
+        // Evaluation is combinatorial, so keep the hands to a sensible size.
+        if cards.len() <= 9 {
+            evaluate(&cards);
+        }
+    }
+});
@@ -0,0 +1,215 @@
+use crate::{Card, Composition, Evaluation, HandRank, Rank, Suit};
+
+/// A standard deck hand built up a card at a time. Adding or removing a card
+/// is constant time, and the best hand can be read off at any point, so a
+/// simulation running down a board doesn't have to evaluate from scratch on
+/// every street.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Accumulator {
+    counts: [u8; 13],
+    // The ranks held in each suit.
+    suited: [u16; 4],
+    len: usize,
+}
+
+impl Accumulator {
+    pub fn new() -> Accumulator {
+        Accumulator::default()
+    }
+
+    /// Returns false, changing nothing, if the card is already held.
+    pub fn add(&mut self, card: Card) -> bool {
+        if self.contains(card) {
+            return false;
+        }
+        self.counts[card.rank as usize] += 1;
+        self.suited[card.suit as usize] |= 1 << card.rank as u16;
+        self.len += 1;
+        true
+    }
+
+    /// Returns false, changing nothing, if the card isn't held.
+    pub fn remove(&mut self, card: Card) -> bool {
+        if !self.contains(card) {
+            return false;
+        }
+        self.counts[card.rank as usize] -= 1;
+        self.suited[card.suit as usize] &= !(1 << card.rank as u16);
+        self.len -= 1;
+        true
+    }
+
+    pub fn contains(&self, card: Card) -> bool {
+        self.suited[card.suit as usize] >> card.rank as u16 & 1 == 1
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The cards held, in the order of [`Card::all`].
+    #[cfg(feature = "std")]
+    pub fn cards(&self) -> Vec<Card> {
+        Card::all().filter(|&card| self.contains(card)).collect()
+    }
+
+    /// The category and strength of the best five cards, as in
+    /// [`Evaluation`], or `None` with fewer than five.
+    pub fn strength(&self) -> Option<(HandRank, u32)> {
+        (self.len >= 5).then(|| best_hand(&self.counts, &self.suited))
+    }
+
+    /// The best five cards, as [`crate::evaluate`] would find them. Where
+    /// suits make no difference the cards picked may not be the same ones.
+    pub fn evaluate(&self) -> Option<Evaluation> {
+        let (category, strength) = self.strength()?;
+        let mut suited = self.suited;
+
+        // Each card's value as packed into the strength, most significant
+        // first, taking the lowest suit left for each.
+        let flush = matches!(category, HandRank::Flush | HandRank::StraightFlush)
+            .then(|| {
+                suited
+                    .iter()
+                    .position(|&ranks| ranks.count_ones() >= 5 && contains_values(ranks, strength))
+            })
+            .flatten();
+        let cards = [16, 12, 8, 4, 0].map(|shift| {
+            let rank = match strength >> shift & 0xf {
+                0 => Rank::Ace,
+                value => Rank::ALL[value as usize - 1],
+            };
+            let suit = match flush {
+                Some(suit) => suit,
+                None => (0..4)
+                    .find(|&suit| suited[suit] >> rank as u16 & 1 == 1)
+                    .expect("held"),
+            };
+            suited[suit] &= !(1 << rank as u16);
+            Card::new(rank, Suit::ALL[suit])
+        });
+
+        Some(Evaluation::from_cards(cards, &Composition::standard()))
+    }
+}
+
+impl FromIterator<Card> for Accumulator {
+    fn from_iter<I: IntoIterator<Item = Card>>(cards: I) -> Accumulator {
+        let mut accumulator = Accumulator::new();
+        accumulator.extend(cards);
+        accumulator
+    }
+}
+
+impl Extend<Card> for Accumulator {
+    fn extend<I: IntoIterator<Item = Card>>(&mut self, cards: I) {
+        for card in cards {
+            self.add(card);
+        }
+    }
+}
+
+// Whether a suit holds every card packed into a strength, which is how a
+// flush or straight flush's suit is told apart from the others.
+fn contains_values(ranks: u16, strength: u32) -> bool {
+    [16, 12, 8, 4, 0].iter().all(|shift| {
+        let rank = match strength >> shift & 0xf {
+            0 => Rank::Ace as u16,
+            value => value as u16 - 1,
+        };
+        ranks >> rank & 1 == 1
+    })
+}
+
+// The category and strength of the best five of any number of cards, from
+// how many there are of each rank and which ranks each suit holds, trying
+// the categories strongest first.
+pub(crate) fn best_hand(counts: &[u8; 13], suited: &[u16; 4]) -> (HandRank, u32) {
+    let pack = |category: HandRank, values: [u32; 5]| {
+        let strength = values
+            .iter()
+            .fold(category as u32, |strength, value| strength << 4 | value);
+        (category, strength)
+    };
+    // Card values as packed into a strength: the rank offset by one.
+    let ranks_with = |n: u8| {
+        (0..13)
+            .rev()
+            .filter(move |&rank| counts[rank] >= n)
+            .map(|rank| rank as u32 + 1)
+    };
+    let kickers = |skip: [u32; 2]| ranks_with(1).filter(move |value| !skip.contains(value));
+    // With more than seven cards there can be more than one flush.
+    let flushes = || suited.iter().filter(|ranks| ranks.count_ones() >= 5);
+
+    if let Some(high) = flushes().filter_map(|&flush| straight(flush)).max() {
+        return pack(HandRank::StraightFlush, high);
+    }
+
+    if let Some(quad) = ranks_with(4).next() {
+        let kicker = kickers([quad, quad]).next().unwrap();
+        return pack(HandRank::FourOfAKind, [quad, quad, quad, quad, kicker]);
+    }
+    let trips = ranks_with(3).next();
+    if let Some(trip) = trips {
+        // The best pair may be a second set of trips.
+        if let Some(pair) = ranks_with(2).find(|&pair| pair != trip) {
+            return pack(HandRank::FullHouse, [trip, trip, trip, pair, pair]);
+        }
+    }
+
+    let best_flush = flushes()
+        .map(|&flush| {
+            let mut values = (0..13)
+                .rev()
+                .filter(|&rank| flush >> rank & 1 == 1)
+                .map(|rank| rank + 1);
+            [(); 5].map(|_| values.next().unwrap())
+        })
+        .max();
+    if let Some(values) = best_flush {
+        return pack(HandRank::Flush, values);
+    }
+    let ranks = suited.iter().fold(0, |ranks, suit| ranks | suit);
+    if let Some(high) = straight(ranks) {
+        return pack(HandRank::Straight, high);
+    }
+
+    if let Some(trip) = trips {
+        let mut k = kickers([trip, trip]);
+        let [a, b] = [(); 2].map(|_| k.next().unwrap());
+        return pack(HandRank::ThreeOfAKind, [trip, trip, trip, a, b]);
+    }
+    let mut pairs = ranks_with(2);
+    match (pairs.next(), pairs.next()) {
+        (Some(high), Some(low)) => {
+            let kicker = kickers([high, low]).next().unwrap();
+            pack(HandRank::TwoPair, [high, high, low, low, kicker])
+        }
+        (Some(pair), None) => {
+            let mut k = kickers([pair, pair]);
+            let [a, b, c] = [(); 3].map(|_| k.next().unwrap());
+            pack(HandRank::OnePair, [pair, pair, a, b, c])
+        }
+        _ => {
+            // No card is worth nothing outside a five high straight.
+            let mut k = kickers([0, 0]);
+            pack(HandRank::HighCard, [(); 5].map(|_| k.next().unwrap()))
+        }
+    }
+}
+
+// The packed values of the highest straight in a rank mask, with the ace
+// worth nothing in a five high straight.
+fn straight(ranks: u16) -> Option<[u32; 5]> {
+    let wheel = 0b1_0000_0000_1111;
+    (4..13)
+        .rev()
+        .find(|&high| ranks >> (high - 4) & 0b11111 == 0b11111)
+        .map(|high| [high + 1, high, high - 1, high - 2, high - 3])
+        .or((ranks & wheel == wheel).then_some([4, 3, 2, 1, 0]))
+}
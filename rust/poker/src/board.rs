@@ -0,0 +1,254 @@
+use std::fmt;
+
+use crate::combinations::Combinations;
+use crate::{evaluate, Bitfields, Card, Evaluation, Suit};
+
+/// A flop, turn or river: three to five community cards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Board {
+    cards: Vec<Card>,
+    bfs: Bitfields,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Suits {
+    /// Every card is the same suit.
+    Monotone,
+    /// Two or more cards share a suit, but not all of them, so there's at
+    /// least a flush draw.
+    TwoTone,
+    /// No two cards share a suit.
+    Rainbow,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Texture {
+    /// Two or more cards share a rank.
+    pub paired: bool,
+    /// Three or more cards share a rank.
+    pub trips: bool,
+    pub suits: Suits,
+    /// The most board ranks that fit in a single five rank straight. Three
+    /// or more means someone could hold a straight.
+    pub connectedness: u32,
+    pub straight_possible: bool,
+    pub flush_possible: bool,
+}
+
+/// Where a holding stands on a board, against every holding an opponent
+/// could have.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HandStrength {
+    pub hand: Evaluation,
+    pub nuts: Evaluation,
+    /// 1 for the nuts, 2 for the second nuts and so on, counting distinct
+    /// hands any holding could make regardless of blockers.
+    pub nut_rank: usize,
+    /// Fractions of the holdings not blocked by ours that we beat and tie.
+    pub beats: f64,
+    pub ties: f64,
+    /// Every holding not blocked by ours that makes a better hand.
+    pub beaten_by: Vec<[Card; 2]>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BoardError {
+    WrongNumberOfCards(usize),
+    DuplicateCard(Card),
+}
+
+impl fmt::Display for BoardError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BoardError::WrongNumberOfCards(n) => write!(f, "a board has 3 to 5 cards, not {n}"),
+            BoardError::DuplicateCard(card) => write!(f, "{card} appears more than once"),
+        }
+    }
+}
+
+impl std::error::Error for BoardError {}
+
+impl Board {
+    pub fn new(cards: &[Card]) -> Result<Board, BoardError> {
+        if !(3..=5).contains(&cards.len()) {
+            return Err(BoardError::WrongNumberOfCards(cards.len()));
+        }
+        for (i, card) in cards.iter().enumerate() {
+            if cards[..i].contains(card) {
+                return Err(BoardError::DuplicateCard(*card));
+            }
+        }
+
+        let mut bfs = Bitfields::init();
+        for card in cards {
+            bfs.add(card.rank.index(), card.suit.index());
+        }
+
+        Ok(Board {
+            cards: cards.to_vec(),
+            bfs,
+        })
+    }
+
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    pub fn texture(&self) -> Texture {
+        let most_of_a_rank = self
+            .cards
+            .iter()
+            .map(|card| self.bfs.tally_for_rank(card.rank.index()).count_ones())
+            .max()
+            .unwrap_or(0);
+        let most_of_a_suit = Suit::ALL
+            .iter()
+            .map(|&suit| self.cards.iter().filter(|card| card.suit == suit).count())
+            .max()
+            .unwrap_or(0);
+
+        // Put a copy of the ace below the two so the wheel counts too, then
+        // slide a five rank window over the ranks.
+        let ace = u32::from(self.bfs.ranks >> 12 & 1);
+        let ranks = u32::from(self.bfs.ranks) << 1 | ace;
+        let connectedness = (0..=9)
+            .map(|low| (ranks >> low & 0b11111).count_ones())
+            .max()
+            .unwrap_or(0);
+
+        Texture {
+            paired: most_of_a_rank >= 2,
+            trips: most_of_a_rank >= 3,
+            suits: match (self.bfs.suits.count_ones(), most_of_a_suit) {
+                (1, _) => Suits::Monotone,
+                (_, 1) => Suits::Rainbow,
+                _ => Suits::TwoTone,
+            },
+            connectedness,
+            straight_possible: connectedness >= 3,
+            flush_possible: most_of_a_suit >= 3,
+        }
+    }
+
+    /// Every two card holding that doesn't use a board card or any of `dead`.
+    pub fn holdings(&self, dead: &[Card]) -> impl Iterator<Item = [Card; 2]> {
+        let known: Vec<Card> = self.cards.iter().chain(dead).copied().collect();
+        Combinations::remaining(&known, 2).map(|holding| [holding[0], holding[1]])
+    }
+
+    /// The best hand made from `hole` and the board.
+    pub fn best_hand(&self, hole: &[Card]) -> Option<Evaluation> {
+        let cards: Vec<Card> = hole.iter().chain(&self.cards).copied().collect();
+        evaluate(&cards)
+    }
+
+    /// The best hand any holding could make on this board.
+    pub fn nuts(&self) -> Evaluation {
+        self.holdings(&[])
+            .filter_map(|holding| self.best_hand(&holding))
+            .max()
+            .expect("a board leaves plenty of holdings")
+    }
+
+    /// How many holdings, from the cards `hole` doesn't block, make a better
+    /// hand than it.
+    pub fn combos_beating(&self, hole: &[Card; 2]) -> usize {
+        let Some(hero) = self.best_hand(hole) else {
+            return 0;
+        };
+
+        self.holdings(hole)
+            .filter(|holding| {
+                self.best_hand(holding)
+                    .is_some_and(|villain| villain > hero)
+            })
+            .count()
+    }
+
+    /// Rank a holding against everything else that could be held on this
+    /// board, or `None` if it shares a card with the board.
+    pub fn hand_strength(&self, hole: &[Card; 2]) -> Option<HandStrength> {
+        if hole.iter().any(|card| self.cards.contains(card)) || hole[0] == hole[1] {
+            return None;
+        }
+        let hand = self.best_hand(hole)?;
+
+        let mut strengths: Vec<u32> = self
+            .holdings(&[])
+            .filter_map(|holding| self.best_hand(&holding))
+            .map(|evaluation| evaluation.strength)
+            .collect();
+        strengths.sort_unstable_by(|a, b| b.cmp(a));
+        strengths.dedup();
+        let nut_rank = 1 + strengths
+            .iter()
+            .take_while(|&&strength| strength > hand.strength)
+            .count();
+
+        let (mut beats, mut ties, mut total) = (0, 0, 0);
+        let mut beaten_by = vec![];
+        for holding in self.holdings(hole) {
+            let villain = self.best_hand(&holding)?;
+            total += 1;
+            match villain.cmp(&hand) {
+                std::cmp::Ordering::Less => beats += 1,
+                std::cmp::Ordering::Equal => ties += 1,
+                std::cmp::Ordering::Greater => beaten_by.push(holding),
+            }
+        }
+
+        Some(HandStrength {
+            hand,
+            nuts: self.nuts(),
+            nut_rank,
+            beats: f64::from(beats) / f64::from(total),
+            ties: f64::from(ties) / f64::from(total),
+            beaten_by,
+        })
+    }
+}
+
+// English ordinal suffix for a positive number.
+fn ordinal(n: usize) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{n}{suffix}")
+}
+
+impl fmt::Display for HandStrength {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.nut_rank {
+            1 => write!(f, "the nuts")?,
+            n => write!(f, "{} nuts", ordinal(n))?,
+        }
+        write!(f, ", beats {:.1}% of combos", self.beats * 100.0)
+    }
+}
+
+impl Texture {
+    /// Short labels for the texture, for filing hands by board type.
+    pub fn tags(&self) -> Vec<&'static str> {
+        let mut tags = vec![match self.suits {
+            Suits::Monotone => "monotone",
+            Suits::TwoTone => "two-tone",
+            Suits::Rainbow => "rainbow",
+        }];
+        if self.trips {
+            tags.push("trips");
+        } else if self.paired {
+            tags.push("paired");
+        }
+        if self.straight_possible {
+            tags.push("straight-possible");
+        }
+        if self.flush_possible {
+            tags.push("flush-possible");
+        }
+        tags
+    }
+}
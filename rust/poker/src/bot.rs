@@ -0,0 +1,100 @@
+use crate::{evaluate, Action, Card, GameState, Rng, Street};
+
+/// Something that can play a seat: given the state of the game from its
+/// seat, decide what to do.
+pub trait Bot {
+    fn act(&mut self, state: &GameState) -> Action;
+}
+
+/// Checks when it can and calls everything else.
+#[derive(Debug, Clone, Default)]
+pub struct CallingStation;
+
+impl Bot for CallingStation {
+    fn act(&mut self, state: &GameState) -> Action {
+        match state.to_call {
+            0 => Action::Check,
+            _ => Action::Call,
+        }
+    }
+}
+
+/// A simple rule-based player. Tight bots need a better hand to play, and
+/// aggressive bots bet and raise their good hands (and, if loose, sometimes
+/// their bad ones) where passive bots just call.
+#[derive(Debug, Clone)]
+pub struct RuleBot {
+    pub tight: bool,
+    pub aggressive: bool,
+    rng: Rng,
+}
+
+impl RuleBot {
+    pub fn new(tight: bool, aggressive: bool, seed: u64) -> RuleBot {
+        RuleBot {
+            tight,
+            aggressive,
+            rng: Rng::new(seed),
+        }
+    }
+
+    pub fn style(&self) -> &'static str {
+        match (self.tight, self.aggressive) {
+            (true, true) => "tight-aggressive",
+            (false, true) => "loose-aggressive",
+            (true, false) => "tight-passive",
+            (false, false) => "loose-passive",
+        }
+    }
+}
+
+impl Bot for RuleBot {
+    fn act(&mut self, state: &GameState) -> Action {
+        let strength = hand_strength(state);
+        let threshold = if self.tight { 0.55 } else { 0.35 };
+        let bluffing = self.aggressive && !self.tight && self.rng.next_f64() < 0.1;
+
+        let bet = state.bets[state.seat];
+        let raise_to = (state.min_raise_to + state.pot / 2).min(state.max_raise_to);
+        let can_raise = state.max_raise_to > bet + state.to_call;
+        let wants_raise = self.aggressive && (strength >= threshold + 0.2 || bluffing);
+
+        match state.to_call {
+            _ if wants_raise && can_raise => Action::Raise(raise_to),
+            0 => Action::Check,
+            _ if strength >= threshold => Action::Call,
+            // Passive bots will look at a cheap flop.
+            to_call
+                if !self.aggressive
+                    && to_call <= state.big_blind
+                    && state.street == Street::Preflop =>
+            {
+                Action::Call
+            }
+            _ => Action::Fold,
+        }
+    }
+}
+
+// A rough 0..1 score for how good a seat's cards are.
+fn hand_strength(state: &GameState) -> f64 {
+    let [a, b] = state.hole;
+    let (hi, lo) = (a.rank.max(b.rank) as u32, a.rank.min(b.rank) as u32);
+
+    if state.board.is_empty() {
+        return match hi == lo {
+            true => 0.5 + f64::from(hi) / 24.0,
+            false => {
+                let suited = if a.suit == b.suit { 0.05 } else { 0.0 };
+                let connected = if hi - lo == 1 { 0.05 } else { 0.0 };
+                f64::from(hi + lo) / 24.0 * 0.6 + suited + connected
+            }
+        };
+    }
+
+    let all: Vec<Card> = state.hole.iter().chain(&state.board).copied().collect();
+    match evaluate(&all).map(|evaluation| evaluation.category as u32) {
+        Some(category) => (0.2 + f64::from(category - 1) * 0.15).min(1.0),
+        None => 0.0,
+    }
+}
@@ -0,0 +1,322 @@
+use core::fmt;
+#[cfg(feature = "std")]
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Card rank, lowest first. The discriminant is the bit position used by
+/// the evaluator's rank masks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(into = "String", try_from = "String")
+)]
+pub enum Rank {
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+/// Card suit. The discriminant is the bit position used by the evaluator's
+/// suit masks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(into = "String", try_from = "String")
+)]
+pub enum Suit {
+    Spades,
+    Hearts,
+    Diamonds,
+    Clubs,
+}
+
+/// A playing card. Parsing one from a string such as `"10S"` needs the `std`
+/// feature; without it, build cards with [`Card::new`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(into = "String", try_from = "String")
+)]
+pub struct Card {
+    pub rank: Rank,
+    pub suit: Suit,
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseCardError {
+    InvalidRank(String),
+    InvalidSuit(String),
+}
+
+impl Rank {
+    pub const ALL: [Rank; 13] = [
+        Rank::Two,
+        Rank::Three,
+        Rank::Four,
+        Rank::Five,
+        Rank::Six,
+        Rank::Seven,
+        Rank::Eight,
+        Rank::Nine,
+        Rank::Ten,
+        Rank::Jack,
+        Rank::Queen,
+        Rank::King,
+        Rank::Ace,
+    ];
+
+    pub fn from_index(index: u16) -> Option<Rank> {
+        Rank::ALL.get(usize::from(index)).copied()
+    }
+
+    pub fn index(self) -> u16 {
+        self as u16
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Rank::Two => "two",
+            Rank::Three => "three",
+            Rank::Four => "four",
+            Rank::Five => "five",
+            Rank::Six => "six",
+            Rank::Seven => "seven",
+            Rank::Eight => "eight",
+            Rank::Nine => "nine",
+            Rank::Ten => "ten",
+            Rank::Jack => "jack",
+            Rank::Queen => "queen",
+            Rank::King => "king",
+            Rank::Ace => "ace",
+        }
+    }
+
+    pub fn plural(self) -> &'static str {
+        match self {
+            Rank::Two => "twos",
+            Rank::Three => "threes",
+            Rank::Four => "fours",
+            Rank::Five => "fives",
+            Rank::Six => "sixes",
+            Rank::Seven => "sevens",
+            Rank::Eight => "eights",
+            Rank::Nine => "nines",
+            Rank::Ten => "tens",
+            Rank::Jack => "jacks",
+            Rank::Queen => "queens",
+            Rank::King => "kings",
+            Rank::Ace => "aces",
+        }
+    }
+}
+
+impl Suit {
+    pub const ALL: [Suit; 4] = [Suit::Spades, Suit::Hearts, Suit::Diamonds, Suit::Clubs];
+
+    pub fn from_index(index: u16) -> Option<Suit> {
+        Suit::ALL.get(usize::from(index)).copied()
+    }
+
+    pub fn index(self) -> u16 {
+        self as u16
+    }
+}
+
+impl Card {
+    pub fn new(rank: Rank, suit: Suit) -> Card {
+        Card { rank, suit }
+    }
+
+    /// All 52 cards of a standard deck, ordered by rank then suit.
+    pub fn all() -> impl Iterator<Item = Card> {
+        Rank::ALL
+            .into_iter()
+            .flat_map(|rank| Suit::ALL.into_iter().map(move |suit| Card { rank, suit }))
+    }
+}
+
+#[cfg(feature = "std")]
+/// Parse a whitespace-separated list of cards, e.g. `"4S 5S 7H 8D 10C"`.
+pub fn parse_cards(cards: &str) -> Result<Vec<Card>, ParseCardError> {
+    cards.split_whitespace().map(str::parse).collect()
+}
+
+impl fmt::Display for Rank {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Rank::Two => "2",
+            Rank::Three => "3",
+            Rank::Four => "4",
+            Rank::Five => "5",
+            Rank::Six => "6",
+            Rank::Seven => "7",
+            Rank::Eight => "8",
+            Rank::Nine => "9",
+            Rank::Ten => "10",
+            Rank::Jack => "J",
+            Rank::Queen => "Q",
+            Rank::King => "K",
+            Rank::Ace => "A",
+        };
+        f.write_str(s)
+    }
+}
+
+impl fmt::Display for Suit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Suit::Spades => "S",
+            Suit::Hearts => "H",
+            Suit::Diamonds => "D",
+            Suit::Clubs => "C",
+        };
+        f.write_str(s)
+    }
+}
+
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.rank, self.suit)
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for ParseCardError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseCardError::InvalidRank(s) => write!(f, "invalid card rank in {s:?}"),
+            ParseCardError::InvalidSuit(s) => write!(f, "invalid card suit in {s:?}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseCardError {}
+
+#[cfg(feature = "std")]
+impl FromStr for Rank {
+    type Err = ParseCardError;
+
+    fn from_str(s: &str) -> Result<Rank, ParseCardError> {
+        match s {
+            "2" => Ok(Rank::Two),
+            "3" => Ok(Rank::Three),
+            "4" => Ok(Rank::Four),
+            "5" => Ok(Rank::Five),
+            "6" => Ok(Rank::Six),
+            "7" => Ok(Rank::Seven),
+            "8" => Ok(Rank::Eight),
+            "9" => Ok(Rank::Nine),
+            "10" => Ok(Rank::Ten),
+            "J" => Ok(Rank::Jack),
+            "Q" => Ok(Rank::Queen),
+            "K" => Ok(Rank::King),
+            "A" => Ok(Rank::Ace),
+            _ => Err(ParseCardError::InvalidRank(s.to_string())),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl FromStr for Suit {
+    type Err = ParseCardError;
+
+    fn from_str(s: &str) -> Result<Suit, ParseCardError> {
+        match s {
+            "S" => Ok(Suit::Spades),
+            "H" => Ok(Suit::Hearts),
+            "D" => Ok(Suit::Diamonds),
+            "C" => Ok(Suit::Clubs),
+            _ => Err(ParseCardError::InvalidSuit(s.to_string())),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl FromStr for Card {
+    type Err = ParseCardError;
+
+    // The suit is always the last character, everything before it is the rank.
+    fn from_str(s: &str) -> Result<Card, ParseCardError> {
+        let split = s
+            .char_indices()
+            .last()
+            .map(|(i, _)| i)
+            .ok_or_else(|| ParseCardError::InvalidRank(s.to_string()))?;
+        let (rank, suit) = s.split_at(split);
+        let rank = rank
+            .parse()
+            .map_err(|_| ParseCardError::InvalidRank(s.to_string()))?;
+        let suit = suit
+            .parse()
+            .map_err(|_| ParseCardError::InvalidSuit(s.to_string()))?;
+
+        Ok(Card { rank, suit })
+    }
+}
+
+// Conversions used for the string-based serde representation.
+
+#[cfg(feature = "std")]
+impl From<Rank> for String {
+    fn from(rank: Rank) -> String {
+        rank.to_string()
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<Suit> for String {
+    fn from(suit: Suit) -> String {
+        suit.to_string()
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<Card> for String {
+    fn from(card: Card) -> String {
+        card.to_string()
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<String> for Rank {
+    type Error = ParseCardError;
+
+    fn try_from(s: String) -> Result<Rank, ParseCardError> {
+        s.parse()
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<String> for Suit {
+    type Error = ParseCardError;
+
+    fn try_from(s: String) -> Result<Suit, ParseCardError> {
+        s.parse()
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<String> for Card {
+    type Error = ParseCardError;
+
+    fn try_from(s: String) -> Result<Card, ParseCardError> {
+        s.parse()
+    }
+}
@@ -0,0 +1,691 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::preflop::rank_char;
+use crate::{HeadsUpEquities, Rank, StartingHand};
+
+/// What happens at a state of a game.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Node<S, I> {
+    /// The game is over. The payoff is to the first player, and the second
+    /// player gets the negative of it.
+    Terminal(f64),
+    /// The next state is picked at random with the given probabilities.
+    Chance(Vec<(f64, S)>),
+    /// A player picks one of the named actions. States the player can't
+    /// tell apart share an information set.
+    Decision {
+        player: usize,
+        info_set: I,
+        actions: Vec<(&'static str, S)>,
+    },
+}
+
+/// A two player, zero-sum game small enough to visit every state of.
+pub trait GameTree {
+    type State: Clone;
+    type InfoSet: Clone + Eq + Hash;
+
+    fn root(&self) -> Self::State;
+    fn node(&self, state: &Self::State) -> Node<Self::State, Self::InfoSet>;
+}
+
+/// How often to take each action in every information set.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Strategy<I: Eq + Hash> {
+    actions: HashMap<I, Vec<(&'static str, f64)>>,
+}
+
+impl<I: Eq + Hash> Strategy<I> {
+    pub fn get(&self, info_set: &I) -> Option<&[(&'static str, f64)]> {
+        self.actions.get(info_set).map(Vec::as_slice)
+    }
+
+    pub fn probability(&self, info_set: &I, action: &str) -> Option<f64> {
+        self.get(info_set)?
+            .iter()
+            .find(|(name, _)| *name == action)
+            .map(|&(_, probability)| probability)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&I, &[(&'static str, f64)])> {
+        self.actions
+            .iter()
+            .map(|(info_set, actions)| (info_set, actions.as_slice()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.actions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+
+    // Uniformly random in information sets the strategy doesn't cover.
+    fn probabilities(&self, info_set: &I, actions: usize) -> Vec<f64> {
+        match self.actions.get(info_set) {
+            Some(probabilities) => probabilities.iter().map(|&(_, p)| p).collect(),
+            None => vec![1.0 / actions as f64; actions],
+        }
+    }
+}
+
+/// Counterfactual regret minimisation, in its CFR+ form: negative regrets
+/// are forgotten, the players update in turn and later iterations count for
+/// more in the average strategy, which is what converges to an equilibrium.
+pub struct Cfr<G: GameTree> {
+    game: G,
+    info_sets: HashMap<G::InfoSet, Regrets>,
+    iterations: usize,
+}
+
+struct Regrets {
+    actions: Vec<&'static str>,
+    regrets: Vec<f64>,
+    // This iteration's regrets, added in once the whole tree has been seen
+    // so that one visit's don't change the strategy on the next.
+    pending: Vec<f64>,
+    strategy_sum: Vec<f64>,
+}
+
+impl Regrets {
+    fn new<S>(actions: &[(&'static str, S)]) -> Regrets {
+        Regrets {
+            actions: actions.iter().map(|&(name, _)| name).collect(),
+            regrets: vec![0.0; actions.len()],
+            pending: vec![0.0; actions.len()],
+            strategy_sum: vec![0.0; actions.len()],
+        }
+    }
+
+    // Each action in proportion to how much we regret not taking it, or
+    // uniformly when there's nothing to regret.
+    fn current(&self) -> Vec<f64> {
+        normalise(&self.regrets)
+    }
+
+    fn average(&self) -> Vec<f64> {
+        normalise(&self.strategy_sum)
+    }
+}
+
+fn normalise(weights: &[f64]) -> Vec<f64> {
+    let total: f64 = weights.iter().sum();
+    if total > 0.0 {
+        weights.iter().map(|weight| weight / total).collect()
+    } else {
+        vec![1.0 / weights.len() as f64; weights.len()]
+    }
+}
+
+impl<G: GameTree> Cfr<G> {
+    pub fn new(game: G) -> Cfr<G> {
+        Cfr {
+            game,
+            info_sets: HashMap::new(),
+            iterations: 0,
+        }
+    }
+
+    pub fn game(&self) -> &G {
+        &self.game
+    }
+
+    pub fn iterations(&self) -> usize {
+        self.iterations
+    }
+
+    pub fn run(&mut self, iterations: usize) {
+        for _ in 0..iterations {
+            self.iterations += 1;
+            for player in 0..2 {
+                let root = self.game.root();
+                self.walk(&root, player, 1.0, 1.0);
+                for regrets in self.info_sets.values_mut() {
+                    for (regret, pending) in regrets.regrets.iter_mut().zip(&mut regrets.pending) {
+                        *regret = (*regret + *pending).max(0.0);
+                        *pending = 0.0;
+                    }
+                }
+            }
+        }
+    }
+
+    // The value of a state to `player`, who plays to it with probability
+    // `reach` while chance and the opponent do with probability `others`,
+    // updating the player's regrets below it.
+    fn walk(&mut self, state: &G::State, player: usize, reach: f64, others: f64) -> f64 {
+        match self.game.node(state) {
+            Node::Terminal(payoff) if player == 0 => payoff,
+            Node::Terminal(payoff) => -payoff,
+            Node::Chance(outcomes) => outcomes
+                .iter()
+                .map(|(p, next)| p * self.walk(next, player, reach, others * p))
+                .sum(),
+            Node::Decision {
+                player: actor,
+                info_set,
+                actions,
+            } => {
+                let strategy = self
+                    .info_sets
+                    .entry(info_set.clone())
+                    .or_insert_with(|| Regrets::new(&actions))
+                    .current();
+
+                if actor != player {
+                    return actions
+                        .iter()
+                        .zip(&strategy)
+                        .filter(|(_, &p)| p > 0.0)
+                        .map(|((_, next), &p)| p * self.walk(next, player, reach, others * p))
+                        .sum();
+                }
+
+                let values: Vec<f64> = actions
+                    .iter()
+                    .zip(&strategy)
+                    .map(|((_, next), &p)| self.walk(next, player, reach * p, others))
+                    .collect();
+                let value: f64 = values.iter().zip(&strategy).map(|(v, p)| v * p).sum();
+
+                let weight = self.iterations as f64;
+                let regrets = &mut self.info_sets.get_mut(&info_set).expect("added above");
+                for (a, action_value) in values.iter().enumerate() {
+                    regrets.pending[a] += others * (action_value - value);
+                    regrets.strategy_sum[a] += weight * reach * strategy[a];
+                }
+                value
+            }
+        }
+    }
+
+    /// The average strategy so far.
+    pub fn strategy(&self) -> Strategy<G::InfoSet> {
+        let actions = self
+            .info_sets
+            .iter()
+            .map(|(info_set, regrets)| {
+                let average = regrets.actions.iter().copied().zip(regrets.average());
+                (info_set.clone(), average.collect())
+            })
+            .collect();
+        Strategy { actions }
+    }
+
+    /// How far the average strategy is from an equilibrium.
+    pub fn exploitability(&self) -> f64 {
+        exploitability(&self.game, &self.strategy())
+    }
+}
+
+/// The first player's expected payoff when both play `strategy`.
+pub fn expected_value<G: GameTree>(game: &G, strategy: &Strategy<G::InfoSet>) -> f64 {
+    fn value<G: GameTree>(game: &G, strategy: &Strategy<G::InfoSet>, state: &G::State) -> f64 {
+        match game.node(state) {
+            Node::Terminal(payoff) => payoff,
+            Node::Chance(outcomes) => outcomes
+                .iter()
+                .map(|(p, next)| p * value(game, strategy, next))
+                .sum(),
+            Node::Decision {
+                info_set, actions, ..
+            } => strategy
+                .probabilities(&info_set, actions.len())
+                .iter()
+                .zip(&actions)
+                .filter(|(&p, _)| p > 0.0)
+                .map(|(p, (_, next))| p * value(game, strategy, next))
+                .sum(),
+        }
+    }
+
+    value(game, strategy, &game.root())
+}
+
+/// The average of what each player could win by switching to the best
+/// response to the other's half of `strategy`. It is zero exactly at an
+/// equilibrium.
+pub fn exploitability<G: GameTree>(game: &G, strategy: &Strategy<G::InfoSet>) -> f64 {
+    (best_response_value(game, strategy, 0) + best_response_value(game, strategy, 1)) / 2.0
+}
+
+/// The most `player` can expect to win against the other player's half of
+/// `strategy`.
+pub fn best_response_value<G: GameTree>(
+    game: &G,
+    strategy: &Strategy<G::InfoSet>,
+    player: usize,
+) -> f64 {
+    let mut response = BestResponse {
+        game,
+        strategy,
+        player,
+        histories: HashMap::new(),
+        chosen: HashMap::new(),
+    };
+    let root = game.root();
+    response.collect(&root, 1.0);
+    response.value(&root)
+}
+
+struct BestResponse<'a, G: GameTree> {
+    game: &'a G,
+    strategy: &'a Strategy<G::InfoSet>,
+    player: usize,
+    // Every state in each of the player's information sets, with the
+    // chance of the opponent and chance reaching it.
+    histories: HashMap<G::InfoSet, Vec<(G::State, f64)>>,
+    // The best action in each information set, once worked out.
+    chosen: HashMap<G::InfoSet, usize>,
+}
+
+impl<G: GameTree> BestResponse<'_, G> {
+    fn collect(&mut self, state: &G::State, reach: f64) {
+        match self.game.node(state) {
+            Node::Terminal(_) => {}
+            Node::Chance(outcomes) => {
+                for (p, next) in &outcomes {
+                    self.collect(next, reach * p);
+                }
+            }
+            Node::Decision {
+                player,
+                info_set,
+                actions,
+            } if player == self.player => {
+                self.histories
+                    .entry(info_set)
+                    .or_default()
+                    .push((state.clone(), reach));
+                for (_, next) in &actions {
+                    self.collect(next, reach);
+                }
+            }
+            Node::Decision {
+                info_set, actions, ..
+            } => {
+                let probabilities = self.strategy.probabilities(&info_set, actions.len());
+                for ((_, next), p) in actions.iter().zip(probabilities) {
+                    if p > 0.0 {
+                        self.collect(next, reach * p);
+                    }
+                }
+            }
+        }
+    }
+
+    fn value(&mut self, state: &G::State) -> f64 {
+        match self.game.node(state) {
+            Node::Terminal(payoff) if self.player == 0 => payoff,
+            Node::Terminal(payoff) => -payoff,
+            Node::Chance(outcomes) => outcomes.iter().map(|(p, next)| p * self.value(next)).sum(),
+            Node::Decision {
+                player,
+                info_set,
+                actions,
+            } if player == self.player => {
+                let action = self.choose(&info_set);
+                self.value(&actions[action].1)
+            }
+            Node::Decision {
+                info_set, actions, ..
+            } => {
+                let probabilities = self.strategy.probabilities(&info_set, actions.len());
+                actions
+                    .iter()
+                    .zip(probabilities)
+                    .filter(|&(_, p)| p > 0.0)
+                    .map(|((_, next), p)| p * self.value(next))
+                    .sum()
+            }
+        }
+    }
+
+    // The action with the most value summed over every state in the
+    // information set. Perfect recall means the states below only lead to
+    // information sets further down, so this always finishes.
+    fn choose(&mut self, info_set: &G::InfoSet) -> usize {
+        if let Some(&action) = self.chosen.get(info_set) {
+            return action;
+        }
+
+        let histories = self.histories.get(info_set).cloned().unwrap_or_default();
+        let mut totals: Vec<f64> = vec![];
+        for (state, reach) in &histories {
+            if let Node::Decision { actions, .. } = self.game.node(state) {
+                totals.resize(actions.len(), 0.0);
+                for (a, (_, next)) in actions.iter().enumerate() {
+                    totals[a] += reach * self.value(next);
+                }
+            }
+        }
+
+        let action = (0..totals.len())
+            .max_by(|&a, &b| totals[a].total_cmp(&totals[b]))
+            .unwrap_or(0);
+        self.chosen.insert(info_set.clone(), action);
+        action
+    }
+}
+
+/// Kuhn poker: a jack, a queen and a king, one dealt to each player, an ante
+/// of one and a single bet of one. Information sets are the player's card
+/// then the actions so far, `p` to check or fold and `b` to bet or call,
+/// e.g. "Qpb".
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Kuhn;
+
+#[derive(Debug, Clone)]
+pub struct KuhnState {
+    cards: Option<[Rank; 2]>,
+    history: String,
+}
+
+impl GameTree for Kuhn {
+    type State = KuhnState;
+    type InfoSet = String;
+
+    fn root(&self) -> KuhnState {
+        KuhnState {
+            cards: None,
+            history: String::new(),
+        }
+    }
+
+    fn node(&self, state: &KuhnState) -> Node<KuhnState, String> {
+        let Some(cards) = state.cards else {
+            let ranks = [Rank::Jack, Rank::Queen, Rank::King];
+            let deals = ranks
+                .iter()
+                .flat_map(|&first| ranks.iter().map(move |&second| [first, second]))
+                .filter(|[first, second]| first != second)
+                .map(|cards| {
+                    let state = KuhnState {
+                        cards: Some(cards),
+                        history: String::new(),
+                    };
+                    (1.0 / 6.0, state)
+                })
+                .collect();
+            return Node::Chance(deals);
+        };
+
+        let showdown = |stake: f64| if cards[0] > cards[1] { stake } else { -stake };
+        match state.history.as_str() {
+            "pp" => Node::Terminal(showdown(1.0)),
+            "bb" | "pbb" => Node::Terminal(showdown(2.0)),
+            "bp" => Node::Terminal(1.0),
+            "pbp" => Node::Terminal(-1.0),
+            history => {
+                let player = history.len() % 2;
+                let [pass, bet] = match history.ends_with('b') {
+                    true => ["fold", "call"],
+                    false => ["check", "bet"],
+                };
+                let next = |action: char| KuhnState {
+                    cards: state.cards,
+                    history: format!("{history}{action}"),
+                };
+                Node::Decision {
+                    player,
+                    info_set: format!("{}{history}", cards[player]),
+                    actions: vec![(pass, next('p')), (bet, next('b'))],
+                }
+            }
+        }
+    }
+}
+
+/// Leduc hold'em: two each of jack, queen and king. Each player antes one
+/// and gets a card, there's a round of betting in twos, a shared card and a
+/// round in fours, with a bet and one raise allowed each round. Pairing the
+/// shared card wins, otherwise the higher card does.
+///
+/// Information sets are the player's card and the shared card, then the
+/// actions in each round, `c` to check or call, `r` to bet or raise and `f`
+/// to fold, e.g. "KQ:cr/r".
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Leduc;
+
+#[derive(Debug, Clone)]
+pub struct LeducState {
+    private: Option<[Rank; 2]>,
+    public: Option<Rank>,
+    rounds: [String; 2],
+}
+
+impl GameTree for Leduc {
+    type State = LeducState;
+    type InfoSet = String;
+
+    fn root(&self) -> LeducState {
+        LeducState {
+            private: None,
+            public: None,
+            rounds: [String::new(), String::new()],
+        }
+    }
+
+    fn node(&self, state: &LeducState) -> Node<LeducState, String> {
+        let ranks = [Rank::Jack, Rank::Queen, Rank::King];
+        let Some(private) = state.private else {
+            let deals = ranks
+                .iter()
+                .flat_map(|&first| ranks.iter().map(move |&second| [first, second]))
+                .map(|cards| {
+                    let second = if cards[0] == cards[1] { 1.0 } else { 2.0 };
+                    let state = LeducState {
+                        private: Some(cards),
+                        ..self.root()
+                    };
+                    (2.0 / 6.0 * second / 5.0, state)
+                })
+                .collect();
+            return Node::Chance(deals);
+        };
+
+        let round = usize::from(state.public.is_some());
+        let actions = &state.rounds[round];
+
+        // Replay the betting to find what each player has put in.
+        let mut put_in: [f64; 2] = [1.0, 1.0];
+        for (size, history) in [2.0, 4.0].iter().zip(&state.rounds) {
+            for (i, action) in history.chars().enumerate() {
+                let most = put_in[0].max(put_in[1]);
+                match action {
+                    'r' => put_in[i % 2] = most + size,
+                    'c' => put_in[i % 2] = most,
+                    _ => {}
+                }
+            }
+        }
+
+        if let Some(i) = actions.find('f') {
+            let folder = i % 2;
+            return Node::Terminal(if folder == 0 { -put_in[0] } else { put_in[1] });
+        }
+
+        let round_over = actions.len() >= 2 && actions.ends_with('c');
+        match (round_over, state.public) {
+            (true, None) => {
+                let deals = ranks
+                    .iter()
+                    .map(|&rank| {
+                        let left = 2 - private.iter().filter(|&&card| card == rank).count();
+                        let state = LeducState {
+                            public: Some(rank),
+                            ..state.clone()
+                        };
+                        (left as f64 / 4.0, state)
+                    })
+                    .filter(|&(p, _)| p > 0.0)
+                    .collect();
+                Node::Chance(deals)
+            }
+            (true, Some(public)) => {
+                let hand = |card: Rank| (card == public, card);
+                let payoff = match hand(private[0]).cmp(&hand(private[1])) {
+                    std::cmp::Ordering::Greater => put_in[1],
+                    std::cmp::Ordering::Equal => 0.0,
+                    std::cmp::Ordering::Less => -put_in[0],
+                };
+                Node::Terminal(payoff)
+            }
+            (false, public) => {
+                let player = actions.len() % 2;
+                let next = |action: char| {
+                    let mut next = state.clone();
+                    next.rounds[round].push(action);
+                    next
+                };
+                let raises = actions.matches('r').count();
+                let mut choices = match actions.ends_with('r') {
+                    true => vec![("fold", next('f')), ("call", next('c'))],
+                    false => vec![("check", next('c'))],
+                };
+                if raises < 2 {
+                    let raise = if raises == 0 { "bet" } else { "raise" };
+                    choices.push((raise, next('r')));
+                }
+
+                let public = public.map_or(String::new(), |card| card.to_string());
+                Node::Decision {
+                    player,
+                    info_set: format!(
+                        "{}{public}:{}/{}",
+                        private[player], state.rounds[0], state.rounds[1]
+                    ),
+                    actions: choices,
+                }
+            }
+        }
+    }
+}
+
+/// Heads-up push or fold: the small blind goes all in or folds, then the big
+/// blind calls or folds. Stacks and payoffs are in big blinds, and called
+/// all-ins pay out by the hands' equity against each other.
+///
+/// Information sets are the player (0 for the small blind, 1 for the big
+/// blind) and their starting hand.
+#[derive(Debug, Clone)]
+pub struct PushFold {
+    /// Both players' stacks before the blinds, in big blinds.
+    pub stack: f64,
+    equities: HeadsUpEquities,
+}
+
+#[derive(Debug, Clone)]
+pub struct PushFoldState(Stage);
+
+#[derive(Debug, Clone)]
+enum Stage {
+    Deal,
+    SmallBlind(usize, usize),
+    BigBlind(usize, usize),
+    Over(f64),
+}
+
+impl PushFold {
+    pub fn new(stack: f64, equities: HeadsUpEquities) -> PushFold {
+        PushFold { stack, equities }
+    }
+
+    /// The fraction of all deals a player goes all in or calls with.
+    pub fn range(&self, strategy: &Strategy<(usize, StartingHand)>, player: usize) -> f64 {
+        let action = if player == 0 { "push" } else { "call" };
+        let in_range: f64 = self
+            .equities
+            .hands
+            .iter()
+            .map(|&hand| {
+                let p = strategy.probability(&(player, hand), action).unwrap_or(0.0);
+                p * hand.combos() as f64
+            })
+            .sum();
+        in_range / 1326.0
+    }
+
+    /// The 13x13 chart of how often (as a percentage) a player goes all in
+    /// or calls with each hand.
+    pub fn chart(&self, strategy: &Strategy<(usize, StartingHand)>, player: usize) -> String {
+        let action = if player == 0 { "push" } else { "call" };
+        let ranks: Vec<Rank> = Rank::ALL.iter().rev().copied().collect();
+        let mut chart = String::from("    ");
+        for &rank in &ranks {
+            chart += &format!("{:>5}", rank_char(rank));
+        }
+        chart.push('\n');
+
+        for &row in &ranks {
+            chart += &format!("{:>4}", rank_char(row));
+            for &col in &ranks {
+                let hand = StartingHand::at(row, col);
+                match strategy.probability(&(player, hand), action) {
+                    Some(p) => chart += &format!("{:>5.0}", p * 100.0),
+                    None => chart += "    -",
+                }
+            }
+            chart.push('\n');
+        }
+
+        chart
+    }
+}
+
+impl GameTree for PushFold {
+    type State = PushFoldState;
+    type InfoSet = (usize, StartingHand);
+
+    fn root(&self) -> PushFoldState {
+        PushFoldState(Stage::Deal)
+    }
+
+    fn node(&self, state: &PushFoldState) -> Node<PushFoldState, (usize, StartingHand)> {
+        let hands = &self.equities.hands;
+        match state.0 {
+            Stage::Deal => {
+                let n = hands.len();
+                let pairs = (0..n).flat_map(|i| (0..n).map(move |j| (i, j)));
+                let deals: Vec<(usize, usize, u32)> = pairs
+                    .map(|(i, j)| (i, j, self.equities.by_index(i, j).1))
+                    .filter(|&(_, _, deals)| deals > 0)
+                    .collect();
+                let total: u32 = deals.iter().map(|&(_, _, deals)| deals).sum();
+                let outcomes = deals
+                    .into_iter()
+                    .map(|(i, j, deals)| {
+                        let p = f64::from(deals) / f64::from(total);
+                        (p, PushFoldState(Stage::SmallBlind(i, j)))
+                    })
+                    .collect();
+                Node::Chance(outcomes)
+            }
+            Stage::SmallBlind(i, j) => Node::Decision {
+                player: 0,
+                info_set: (0, hands[i]),
+                actions: vec![
+                    ("fold", PushFoldState(Stage::Over(-0.5))),
+                    ("push", PushFoldState(Stage::BigBlind(i, j))),
+                ],
+            },
+            Stage::BigBlind(i, j) => {
+                let equity = self.equities.by_index(i, j).0;
+                Node::Decision {
+                    player: 1,
+                    info_set: (1, hands[j]),
+                    actions: vec![
+                        ("fold", PushFoldState(Stage::Over(1.0))),
+                        (
+                            "call",
+                            PushFoldState(Stage::Over(self.stack * (2.0 * equity - 1.0))),
+                        ),
+                    ],
+                }
+            }
+            Stage::Over(payoff) => Node::Terminal(payoff),
+        }
+    }
+}
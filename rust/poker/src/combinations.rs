@@ -0,0 +1,95 @@
+#[cfg(feature = "std")]
+use crate::Card;
+
+/// How many ways there are of choosing `k` things from `n`, e.g. 1,326 two
+/// card holdings from a full deck.
+pub fn choose(n: usize, k: usize) -> u64 {
+    if k > n {
+        return 0;
+    }
+    // Each partial product is itself a binomial coefficient, so the division
+    // is always exact.
+    let k = k.min(n - k);
+    (0..k).fold(1, |ways, i| ways * (n - i) as u64 / (i + 1) as u64)
+}
+
+/// The cards left in a standard deck once `dead` are taken out, ordered as
+/// [`Card::all`].
+#[cfg(feature = "std")]
+pub fn remaining_cards(dead: &[Card]) -> Vec<Card> {
+    Card::all().filter(|card| !dead.contains(card)).collect()
+}
+
+/// Every way of choosing `k` of a set of cards, in lexicographic order of
+/// their positions.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct Combinations {
+    cards: Vec<Card>,
+    indices: Vec<usize>,
+    done: bool,
+}
+
+#[cfg(feature = "std")]
+impl Combinations {
+    pub fn new(cards: Vec<Card>, k: usize) -> Combinations {
+        Combinations {
+            done: k > cards.len(),
+            cards,
+            indices: (0..k).collect(),
+        }
+    }
+
+    /// Every `k` cards that could still be dealt from a standard deck once
+    /// `dead` are taken out.
+    pub fn remaining(dead: &[Card], k: usize) -> Combinations {
+        Combinations::new(remaining_cards(dead), k)
+    }
+
+    /// The cards being chosen from.
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+}
+
+#[cfg(feature = "std")]
+impl Iterator for Combinations {
+    type Item = Vec<Card>;
+
+    fn next(&mut self) -> Option<Vec<Card>> {
+        if self.done {
+            return None;
+        }
+        let combination = self.indices.iter().map(|&i| self.cards[i]).collect();
+        self.done = !next_combination(&mut self.indices, self.cards.len());
+        Some(combination)
+    }
+}
+
+/// Every way of completing a hold'em board to five cards from the cards
+/// not on it or in `dead`, as the cards to come. A complete board has one
+/// runout with nothing to come.
+#[cfg(feature = "std")]
+pub fn runouts(board: &[Card], dead: &[Card]) -> Combinations {
+    let known: Vec<Card> = board.iter().chain(dead).copied().collect();
+    Combinations::remaining(&known, 5usize.saturating_sub(board.len()))
+}
+
+// Step `indices` on to the next k-combination of `0..n` in lexicographic
+// order, returning false once the last combination has been passed. Start
+// from `[0, 1, .., k - 1]`.
+pub(crate) fn next_combination(indices: &mut [usize], n: usize) -> bool {
+    let k = indices.len();
+
+    for i in (0..k).rev() {
+        if indices[i] < n - k + i {
+            indices[i] += 1;
+            for j in i + 1..k {
+                indices[j] = indices[j - 1] + 1;
+            }
+            return true;
+        }
+    }
+
+    false
+}
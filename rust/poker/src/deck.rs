@@ -0,0 +1,197 @@
+#[cfg(feature = "std")]
+use crate::{Card, Rng};
+use crate::{HandRank, Rank, Suit, ALL_RANKS};
+
+/// What a deck is made of: which ranks are in it and how many decks are
+/// shuffled together, along with the ranking rules that go with it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Composition {
+    // A bit for each rank in the deck, twos at the bottom.
+    ranks: u16,
+    decks: u8,
+    flush_beats_full_house: bool,
+}
+
+impl Composition {
+    /// One ordinary 52 card deck.
+    pub const fn standard() -> Composition {
+        Composition {
+            ranks: ALL_RANKS,
+            decks: 1,
+            flush_beats_full_house: false,
+        }
+    }
+
+    /// The 36 card short deck, sixes to aces. A,6,7,8,9 is the lowest
+    /// straight, and with fewer flushes about a flush beats a full house.
+    pub fn short_deck() -> Composition {
+        Composition {
+            flush_beats_full_house: true,
+            ..Composition::stripped(Rank::Six)
+        }
+    }
+
+    /// The 48 card Spanish deck, which has no tens. Straights run straight
+    /// over the gap, e.g. 8,9,J,Q,K.
+    pub fn spanish() -> Composition {
+        Composition::standard().without(Rank::Ten)
+    }
+
+    /// A deck with every rank below `lowest` taken out.
+    pub fn stripped(lowest: Rank) -> Composition {
+        Composition {
+            ranks: ALL_RANKS & !((1 << lowest.index()) - 1),
+            ..Composition::standard()
+        }
+    }
+
+    /// The same deck with every card of `rank` taken out.
+    pub fn without(self, rank: Rank) -> Composition {
+        Composition {
+            ranks: self.ranks & !(1 << rank.index()),
+            ..self
+        }
+    }
+
+    /// The same deck shuffled together with `decks - 1` copies of itself.
+    pub fn decks(self, decks: u8) -> Composition {
+        Composition {
+            decks: decks.max(1),
+            ..self
+        }
+    }
+
+    #[cfg(feature = "std")]
+    pub fn ranks(&self) -> Vec<Rank> {
+        Rank::ALL
+            .iter()
+            .copied()
+            .filter(|&rank| self.contains(rank))
+            .collect()
+    }
+
+    pub fn contains(&self, rank: Rank) -> bool {
+        self.ranks >> rank.index() & 1 == 1
+    }
+
+    pub fn deck_count(&self) -> u8 {
+        self.decks
+    }
+
+    pub fn flush_beats_full_house(&self) -> bool {
+        self.flush_beats_full_house
+    }
+
+    /// How many cards there are in all.
+    pub fn len(&self) -> usize {
+        self.ranks.count_ones() as usize * Suit::ALL.len() * usize::from(self.decks)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Every card, deck by deck, each ordered as [`Card::all`].
+    #[cfg(feature = "std")]
+    pub fn cards(&self) -> Vec<Card> {
+        let deck: Vec<Card> = Card::all()
+            .filter(|card| self.contains(card.rank))
+            .collect();
+        deck.repeat(usize::from(self.decks))
+    }
+
+    pub(crate) fn rank_mask(&self) -> u16 {
+        self.ranks
+    }
+
+    // Where a category sits in the order of hands, for packing into a
+    // strength.
+    pub(crate) fn category_value(&self, category: HandRank) -> u32 {
+        match (self.flush_beats_full_house, category) {
+            (true, HandRank::Flush) => HandRank::FullHouse as u32,
+            (true, HandRank::FullHouse) => HandRank::Flush as u32,
+            _ => category as u32,
+        }
+    }
+}
+
+impl Default for Composition {
+    fn default() -> Composition {
+        Composition::standard()
+    }
+}
+
+#[cfg(feature = "std")]
+/// A deck of cards, dealt from the top (the end of the list).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deck {
+    cards: Vec<Card>,
+}
+
+#[cfg(feature = "std")]
+impl Deck {
+    /// A full, ordered 52 card deck.
+    pub fn new() -> Deck {
+        Deck {
+            cards: Card::all().collect(),
+        }
+    }
+
+    /// An ordered deck of the given composition.
+    pub fn with_composition(composition: &Composition) -> Deck {
+        Deck {
+            cards: composition.cards(),
+        }
+    }
+
+    pub fn shuffled(rng: &mut Rng) -> Deck {
+        let mut deck = Deck::new();
+        deck.shuffle(rng);
+        deck
+    }
+
+    pub fn shuffle(&mut self, rng: &mut Rng) {
+        rng.shuffle(&mut self.cards);
+    }
+
+    pub fn deal(&mut self) -> Option<Card> {
+        self.cards.pop()
+    }
+
+    /// Take the given cards out of the deck, e.g. known or dead cards. With
+    /// several decks, one copy is taken out for each time a card is given.
+    pub fn remove(&mut self, cards: &[Card]) {
+        for card in cards {
+            if let Some(i) = self.cards.iter().rposition(|c| c == card) {
+                self.cards.remove(i);
+            }
+        }
+    }
+
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for Deck {
+    fn default() -> Deck {
+        Deck::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<Vec<Card>> for Deck {
+    // The last card in the list is dealt first.
+    fn from(cards: Vec<Card>) -> Deck {
+        Deck { cards }
+    }
+}
@@ -0,0 +1,110 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::combinations::next_combination;
+use crate::{evaluate, Card};
+
+/// Each holding's share of the runouts: outright wins, ties, and the
+/// overall fraction of the pot it can expect (ties split evenly).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Equity {
+    pub win: f64,
+    pub tie: f64,
+    pub equity: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EquityError {
+    NoHoldings,
+    DuplicateCard(Card),
+    TooManyBoardCards(usize),
+    NotEnoughCards,
+}
+
+impl fmt::Display for EquityError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EquityError::NoHoldings => write!(f, "no holdings to compare"),
+            EquityError::DuplicateCard(card) => write!(f, "{card} appears more than once"),
+            EquityError::TooManyBoardCards(n) => {
+                write!(f, "board has {n} cards, at most 5 allowed")
+            }
+            EquityError::NotEnoughCards => write!(f, "not enough cards left to complete the board"),
+        }
+    }
+}
+
+impl std::error::Error for EquityError {}
+
+/// Exact equity for each holding, by enumerating every way to complete the
+/// board from the cards not already held, on the board, or dead.
+pub fn equity(
+    holdings: &[Vec<Card>],
+    board: &[Card],
+    dead: &[Card],
+) -> Result<Vec<Equity>, EquityError> {
+    if holdings.is_empty() {
+        return Err(EquityError::NoHoldings);
+    }
+    if board.len() > 5 {
+        return Err(EquityError::TooManyBoardCards(board.len()));
+    }
+
+    let mut seen = HashSet::new();
+    for &card in holdings.iter().flatten().chain(board).chain(dead) {
+        if !seen.insert(card) {
+            return Err(EquityError::DuplicateCard(card));
+        }
+    }
+
+    let remaining: Vec<Card> = Card::all().filter(|card| !seen.contains(card)).collect();
+    let to_deal = 5 - board.len();
+    if remaining.len() < to_deal {
+        return Err(EquityError::NotEnoughCards);
+    }
+
+    let mut wins = vec![0u64; holdings.len()];
+    let mut ties = vec![0u64; holdings.len()];
+    let mut shares = vec![0f64; holdings.len()];
+    let mut runouts = 0u64;
+
+    let mut indices: Vec<usize> = (0..to_deal).collect();
+    let mut cards = Vec::with_capacity(7);
+    let mut best = Vec::with_capacity(holdings.len());
+    loop {
+        best.clear();
+        for holding in holdings {
+            cards.clear();
+            cards.extend_from_slice(holding);
+            cards.extend_from_slice(board);
+            cards.extend(indices.iter().map(|&i| remaining[i]));
+            best.push(evaluate(&cards).map_or(0, |evaluation| evaluation.strength));
+        }
+
+        let top = best.iter().copied().max().unwrap_or(0);
+        let winners = best.iter().filter(|&&strength| strength == top).count();
+        for (i, &strength) in best.iter().enumerate() {
+            if strength == top {
+                match winners {
+                    1 => wins[i] += 1,
+                    _ => ties[i] += 1,
+                }
+                shares[i] += 1.0 / winners as f64;
+            }
+        }
+        runouts += 1;
+
+        if !next_combination(&mut indices, remaining.len()) {
+            break;
+        }
+    }
+
+    let runouts = runouts as f64;
+    Ok((0..holdings.len())
+        .map(|i| Equity {
+            win: wins[i] as f64 / runouts,
+            tie: ties[i] as f64 / runouts,
+            equity: shares[i] / runouts,
+        })
+        .collect())
+}
@@ -0,0 +1,173 @@
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+use crate::{evaluate, Accumulator, Card, HandRank};
+
+/// A way of finding the best five card hand in a standard deck, so that
+/// simulations can pick one and benchmarks can compare them.
+pub trait Evaluator {
+    /// The category and strength of the best five of `cards`, as in
+    /// [`crate::Evaluation`], or `None` with fewer than five.
+    fn strength(&mut self, cards: &[Card]) -> Option<(HandRank, u32)>;
+}
+
+/// Tries every five cards, as [`evaluate`] does. This is the default.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Exhaustive;
+
+impl Evaluator for Exhaustive {
+    fn strength(&mut self, cards: &[Card]) -> Option<(HandRank, u32)> {
+        evaluate(cards).map(|evaluation| (evaluation.category, evaluation.strength))
+    }
+}
+
+/// Works the best hand out from rank counts and the ranks held in each suit,
+/// as an [`Accumulator`] does, without looking at any five cards on their
+/// own. The cards have to be different.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Incremental;
+
+impl Evaluator for Incremental {
+    fn strength(&mut self, cards: &[Card]) -> Option<(HandRank, u32)> {
+        cards.iter().copied().collect::<Accumulator>().strength()
+    }
+}
+
+/// Remembers what another evaluator made of the last `capacity` different
+/// sets of cards, for work like range against range equity that keeps
+/// coming back to the same boards. The cards have to be different, since
+/// they're looked up by which of the 52 are there.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct Cached<E> {
+    inner: E,
+    capacity: usize,
+    // A slot for each set of cards seen, linked from most to least recently
+    // used so the last one can be dropped when the cache is full.
+    slots: Vec<Slot>,
+    index: HashMap<u64, usize>,
+    newest: Option<usize>,
+    oldest: Option<usize>,
+    hits: u64,
+    misses: u64,
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+struct Slot {
+    cards: u64,
+    strength: Option<(HandRank, u32)>,
+    newer: Option<usize>,
+    older: Option<usize>,
+}
+
+#[cfg(feature = "std")]
+impl<E: Evaluator> Cached<E> {
+    /// Holds at least one result.
+    pub fn new(inner: E, capacity: usize) -> Cached<E> {
+        Cached {
+            inner,
+            capacity: capacity.max(1),
+            slots: vec![],
+            index: HashMap::new(),
+            newest: None,
+            oldest: None,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    pub fn inner(&self) -> &E {
+        &self.inner
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// How many results are held.
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    /// How many lookups were answered from the cache.
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// How many lookups went to the inner evaluator.
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    pub fn clear(&mut self) {
+        self.slots.clear();
+        self.index.clear();
+        self.newest = None;
+        self.oldest = None;
+    }
+
+    fn unlink(&mut self, slot: usize) {
+        let Slot { newer, older, .. } = self.slots[slot];
+        match newer {
+            Some(newer) => self.slots[newer].older = older,
+            None => self.newest = older,
+        }
+        match older {
+            Some(older) => self.slots[older].newer = newer,
+            None => self.oldest = newer,
+        }
+    }
+
+    fn push_newest(&mut self, slot: usize) {
+        self.slots[slot].newer = None;
+        self.slots[slot].older = self.newest;
+        if let Some(newest) = self.newest {
+            self.slots[newest].newer = Some(slot);
+        }
+        self.newest = Some(slot);
+        self.oldest.get_or_insert(slot);
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: Evaluator> Evaluator for Cached<E> {
+    fn strength(&mut self, cards: &[Card]) -> Option<(HandRank, u32)> {
+        let key = cards.iter().fold(0u64, |key, card| {
+            key | 1 << (card.rank as u64 * 4 + card.suit as u64)
+        });
+        if let Some(&slot) = self.index.get(&key) {
+            self.hits += 1;
+            self.unlink(slot);
+            self.push_newest(slot);
+            return self.slots[slot].strength;
+        }
+
+        self.misses += 1;
+        let strength = self.inner.strength(cards);
+        let slot = if self.slots.len() < self.capacity {
+            self.slots.push(Slot {
+                cards: key,
+                strength,
+                newer: None,
+                older: None,
+            });
+            self.slots.len() - 1
+        } else {
+            // Reuse the least recently used slot.
+            let slot = self.oldest.expect("a full cache has an oldest slot");
+            self.unlink(slot);
+            self.index.remove(&self.slots[slot].cards);
+            self.slots[slot].cards = key;
+            self.slots[slot].strength = strength;
+            slot
+        };
+        self.index.insert(key, slot);
+        self.push_newest(slot);
+        strength
+    }
+}
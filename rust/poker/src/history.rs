@@ -0,0 +1,581 @@
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{evaluate, Card, Evaluation, ParseCardError, Street};
+
+/// Where a hand history came from. GGPoker writes the same layout as
+/// PokerStars under a different header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Site {
+    PokerStars,
+    GgPoker,
+    Winamax,
+}
+
+/// One hand of hold'em as an online site recorded it. Amounts are in
+/// hundredths, so $0.25 is 25 and a 1,500 chip stack is 150000.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HandHistory {
+    pub site: Site,
+    pub id: String,
+    pub table: String,
+    pub small_blind: u64,
+    pub big_blind: u64,
+    /// Index into `seats` of the button.
+    pub button: usize,
+    /// In the order the site lists them, which is by seat number.
+    pub seats: Vec<HistorySeat>,
+    pub actions: Vec<HistoryAction>,
+    pub board: Vec<Card>,
+    /// The rake plus any other fees taken from the pot.
+    pub rake: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HistorySeat {
+    /// As numbered at the table.
+    pub number: usize,
+    pub player: String,
+    pub stack: u64,
+    /// The hole cards, if we were dealt them or they were shown.
+    pub hole: Option<[Card; 2]>,
+    /// What they collected from the pot.
+    pub collected: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HistoryAction {
+    pub street: Street,
+    /// Index into [`HandHistory::seats`].
+    pub seat: usize,
+    pub kind: ActionKind,
+}
+
+/// An action from a hand history. Unlike [`crate::Action`] this carries the
+/// amounts, as the site recorded them. Mucking at showdown is recorded as a
+/// fold there.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum ActionKind {
+    /// Chips that don't count towards a bet: antes and dead blinds.
+    Ante(u64),
+    /// A live blind or straddle, which counts towards the preflop bet.
+    Blind(u64),
+    Fold,
+    Check,
+    Call(u64),
+    Bet(u64),
+    /// Raise to this total for the street.
+    Raise(u64),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HistoryError {
+    /// The hand doesn't start with a header from a site we know.
+    UnknownFormat(String),
+    /// Only hold'em can be imported.
+    UnsupportedGame(String),
+    InvalidLine(String),
+    InvalidAmount(String),
+    InvalidCard(ParseCardError),
+}
+
+impl fmt::Display for HistoryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HistoryError::UnknownFormat(line) => write!(f, "not a hand history header: {line}"),
+            HistoryError::UnsupportedGame(line) => write!(f, "not a hold'em hand: {line}"),
+            HistoryError::InvalidLine(line) => write!(f, "could not read line: {line}"),
+            HistoryError::InvalidAmount(amount) => write!(f, "invalid amount: {amount}"),
+            HistoryError::InvalidCard(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for HistoryError {}
+
+/// Why a hand's pot awards don't match its showdown.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AwardError {
+    /// A player went to showdown without their cards being recorded.
+    HandNotShown(String),
+    /// What was collected and raked doesn't add up to the pot.
+    PotMismatch { pot: u64, collected: u64, rake: u64 },
+    /// A player collected other than what their hand won.
+    WrongAward {
+        player: String,
+        expected: u64,
+        collected: u64,
+    },
+}
+
+impl fmt::Display for AwardError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AwardError::HandNotShown(player) => write!(f, "{player}'s hand was not shown"),
+            AwardError::PotMismatch {
+                pot,
+                collected,
+                rake,
+            } => write!(
+                f,
+                "pot of {pot} but {collected} was collected and {rake} raked"
+            ),
+            AwardError::WrongAward {
+                player,
+                expected,
+                collected,
+            } => write!(f, "{player} collected {collected} but won {expected}"),
+        }
+    }
+}
+
+impl std::error::Error for AwardError {}
+
+/// Every hand in a file of hand histories, which may mix sites.
+pub fn parse_histories(text: &str) -> Result<Vec<HandHistory>, HistoryError> {
+    let mut hands: Vec<Vec<&str>> = vec![];
+    for line in text.lines() {
+        let line = line.trim_start_matches('\u{feff}').trim();
+        match hands.last_mut() {
+            _ if site(line).is_some() => hands.push(vec![line]),
+            Some(hand) => hand.push(line),
+            None if line.is_empty() => {}
+            None => return Err(HistoryError::UnknownFormat(line.to_string())),
+        }
+    }
+    hands.iter().map(|lines| parse_hand(lines)).collect()
+}
+
+impl FromStr for HandHistory {
+    type Err = HistoryError;
+
+    fn from_str(s: &str) -> Result<HandHistory, HistoryError> {
+        let lines: Vec<&str> = s
+            .lines()
+            .map(|line| line.trim_start_matches('\u{feff}').trim())
+            .skip_while(|line| line.is_empty())
+            .collect();
+        parse_hand(&lines)
+    }
+}
+
+fn site(header: &str) -> Option<Site> {
+    if header.starts_with("PokerStars ") {
+        Some(Site::PokerStars)
+    } else if header.starts_with("Poker Hand #") {
+        Some(Site::GgPoker)
+    } else if header.starts_with("Winamax Poker") {
+        Some(Site::Winamax)
+    } else {
+        None
+    }
+}
+
+fn parse_hand(lines: &[&str]) -> Result<HandHistory, HistoryError> {
+    let header = lines.first().copied().unwrap_or_default();
+    let site = site(header).ok_or_else(|| HistoryError::UnknownFormat(header.to_string()))?;
+    let lowercase = header.to_lowercase();
+    if !lowercase.contains("hold'em") && !lowercase.contains("holdem") {
+        return Err(HistoryError::UnsupportedGame(header.to_string()));
+    }
+
+    let id = match site {
+        Site::Winamax => header.split_once("HandId: #"),
+        _ => header.split_once('#'),
+    }
+    .and_then(|(_, rest)| rest.split([':', ' ']).next())
+    .ok_or_else(|| HistoryError::InvalidLine(header.to_string()))?;
+
+    // The blinds are in the last bracket with a slash, e.g. "($0.02/$0.05
+    // USD)", or "(25/100/200)" with an ante first.
+    let blinds: Vec<u64> = header
+        .split('(')
+        .filter_map(|group| group.split_once(')'))
+        .map(|(group, _)| group)
+        .rfind(|group| group.contains('/'))
+        .and_then(|group| group.split_whitespace().next())
+        .ok_or_else(|| HistoryError::InvalidLine(header.to_string()))?
+        .split('/')
+        .map(parse_amount)
+        .collect::<Result<_, _>>()?;
+    let [.., small_blind, big_blind] = blinds[..] else {
+        return Err(HistoryError::InvalidLine(header.to_string()));
+    };
+
+    let mut hand = HandHistory {
+        site,
+        id: id.to_string(),
+        table: String::new(),
+        small_blind,
+        big_blind,
+        button: 0,
+        seats: vec![],
+        actions: vec![],
+        board: vec![],
+        rake: 0,
+    };
+    let mut button = None;
+    let mut street = Street::Preflop;
+    let mut summary = false;
+
+    for &line in &lines[1..] {
+        if let Some(section) = line.strip_prefix("*** ") {
+            summary = section.starts_with("SUMMARY");
+            street = match section.split(" ***").next().unwrap_or_default() {
+                "FLOP" => Street::Flop,
+                "TURN" => Street::Turn,
+                "RIVER" => Street::River,
+                "SHOW DOWN" | "SHOWDOWN" => Street::Showdown,
+                _ => street,
+            };
+            if line.contains('[') {
+                hand.board = bracketed_cards(line)?;
+            }
+        } else if summary {
+            if let Some(fees) = line.strip_prefix("Total pot") {
+                // "Total pot $1.52 | Rake $0.07", sometimes with more fees
+                // after, or "| No rake".
+                for fee in fees.split('|').skip(1) {
+                    if let Some(Ok(amount)) = fee.split_whitespace().nth(1).map(parse_amount) {
+                        hand.rake += amount;
+                    }
+                }
+            } else if line.starts_with("Board") {
+                hand.board = bracketed_cards(line)?;
+            }
+        } else if line.starts_with("Table '") || line.starts_with("Table: '") {
+            let name = line.split('\'').nth(1);
+            let number = line
+                .split_once("Seat #")
+                .and_then(|(_, rest)| rest.split(' ').next()?.parse::<usize>().ok());
+            match (name, number) {
+                (Some(name), Some(number)) => {
+                    hand.table = name.to_string();
+                    button = Some(number);
+                }
+                _ => return Err(HistoryError::InvalidLine(line.to_string())),
+            }
+        } else if let Some(seat) = parse_seat(line)? {
+            hand.seats.push(seat);
+        } else if let Some(rest) = line.strip_prefix("Dealt to ") {
+            if let Some((seat, cards)) = hand.find_player(rest) {
+                if cards.contains('[') {
+                    hand.seats[seat].hole = Some(hole_cards(cards, line)?);
+                }
+            }
+        } else if let Some((seat, rest)) = hand.find_player(line) {
+            let words: Vec<&str> = rest.split_whitespace().collect();
+            let mut act = |kind| {
+                hand.actions.push(HistoryAction { street, seat, kind });
+            };
+            match words[..] {
+                ["posts", "small", "&", "big", "blinds", amount, ..] => {
+                    let amount = parse_amount(amount)?;
+                    act(ActionKind::Blind(amount.min(big_blind)));
+                    if amount > big_blind {
+                        act(ActionKind::Ante(amount - big_blind));
+                    }
+                }
+                ["posts", "the", "ante", amount, ..] | ["posts", "ante", amount, ..] => {
+                    act(ActionKind::Ante(parse_amount(amount)?))
+                }
+                ["posts", _, "blind", amount, ..] | ["posts", "straddle", amount, ..] => {
+                    act(ActionKind::Blind(parse_amount(amount)?))
+                }
+                ["folds", ..] | ["mucks", ..] => act(ActionKind::Fold),
+                ["checks", ..] => act(ActionKind::Check),
+                ["calls", amount, ..] => act(ActionKind::Call(parse_amount(amount)?)),
+                ["bets", amount, ..] => act(ActionKind::Bet(parse_amount(amount)?)),
+                ["raises", _, "to", to, ..] => act(ActionKind::Raise(parse_amount(to)?)),
+                ["shows", ..] => hand.seats[seat].hole = Some(hole_cards(rest, line)?),
+                ["collected", amount, ..] => hand.seats[seat].collected += parse_amount(amount)?,
+                // Chat, sitting out, returned bets (which are worked out
+                // from the betting) and the like.
+                _ => {}
+            }
+        }
+    }
+
+    hand.button = button
+        .and_then(|number| hand.seats.iter().position(|seat| seat.number == number))
+        .ok_or_else(|| HistoryError::InvalidLine(header.to_string()))?;
+    Ok(hand)
+}
+
+// "Seat 1: player1 ($5.00 in chips)", or Winamax's "Seat 1: player1 (2€)".
+// Anything else, including lines in the summary, isn't a seat.
+fn parse_seat(line: &str) -> Result<Option<HistorySeat>, HistoryError> {
+    let Some((number, rest)) = line
+        .strip_prefix("Seat ")
+        .and_then(|line| line.split_once(": "))
+    else {
+        return Ok(None);
+    };
+    let Ok(number) = number.parse() else {
+        return Ok(None);
+    };
+
+    // Names can have brackets in too, so take the first that starts with an
+    // amount.
+    let stack = rest.match_indices(" (").find_map(|(i, _)| {
+        let amount = rest[i + 2..].split([' ', ',', ')']).next()?;
+        Some((i, parse_amount(amount).ok()?))
+    });
+    match stack {
+        Some((i, stack)) => Ok(Some(HistorySeat {
+            number,
+            player: rest[..i].to_string(),
+            stack,
+            hole: None,
+            collected: 0,
+        })),
+        None => Err(HistoryError::InvalidLine(line.to_string())),
+    }
+}
+
+impl HandHistory {
+    // The seat whose player starts a line, and the rest of the line. The
+    // longest name wins, in case one player's name starts another's.
+    fn find_player<'a>(&self, line: &'a str) -> Option<(usize, &'a str)> {
+        self.seats
+            .iter()
+            .enumerate()
+            .filter_map(|(i, seat)| {
+                let rest = line.strip_prefix(&seat.player)?;
+                let rest = rest.strip_prefix(": ").or(rest.strip_prefix(' '))?;
+                Some((i, seat.player.len(), rest))
+            })
+            .max_by_key(|&(_, len, _)| len)
+            .map(|(i, _, rest)| (i, rest))
+    }
+
+    /// What each seat put into the pot. A bet nobody called goes back
+    /// whether or not the history says so.
+    pub fn committed(&self) -> Vec<u64> {
+        let mut committed = vec![0; self.seats.len()];
+        for street in [Street::Preflop, Street::Flop, Street::Turn, Street::River] {
+            let mut bets = vec![0; self.seats.len()];
+            for action in self.actions.iter().filter(|action| action.street == street) {
+                let bet = &mut bets[action.seat];
+                match action.kind {
+                    ActionKind::Ante(amount) => committed[action.seat] += amount,
+                    ActionKind::Blind(amount)
+                    | ActionKind::Call(amount)
+                    | ActionKind::Bet(amount) => *bet += amount,
+                    ActionKind::Raise(to) => *bet = to.max(*bet),
+                    ActionKind::Fold | ActionKind::Check => {}
+                }
+            }
+
+            let mut sorted = bets.clone();
+            sorted.sort_unstable_by(|a, b| b.cmp(a));
+            if let [top, second, ..] = sorted[..] {
+                if let Some(bet) = bets.iter_mut().find(|bet| **bet == top) {
+                    *bet = second;
+                }
+            }
+            for (committed, bet) in committed.iter_mut().zip(&bets) {
+                *committed += bet;
+            }
+        }
+        committed
+    }
+
+    pub fn pot(&self) -> u64 {
+        self.committed().iter().sum()
+    }
+
+    /// Each seat's chips won less chips put in, after the rake.
+    pub fn net(&self) -> Vec<i64> {
+        self.seats
+            .iter()
+            .zip(self.committed())
+            .map(|(seat, committed)| seat.collected as i64 - committed as i64)
+            .collect()
+    }
+
+    /// The hands of the seats that went to showdown, by index into `seats`.
+    pub fn showdown(&self) -> Result<Vec<(usize, Evaluation)>, AwardError> {
+        let in_hand: Vec<usize> = (0..self.seats.len())
+            .filter(|&i| self.actions.iter().any(|action| action.seat == i))
+            .filter(|&i| {
+                !self
+                    .actions
+                    .iter()
+                    .any(|action| action.seat == i && action.kind == ActionKind::Fold)
+            })
+            .collect();
+        if in_hand.len() < 2 {
+            return Ok(vec![]);
+        }
+
+        in_hand
+            .into_iter()
+            .map(|i| {
+                let seat = &self.seats[i];
+                let cards: Vec<Card> = seat
+                    .hole
+                    .iter()
+                    .flatten()
+                    .chain(&self.board)
+                    .copied()
+                    .collect();
+                match (seat.hole, evaluate(&cards)) {
+                    (Some(_), Some(evaluation)) => Ok((i, evaluation)),
+                    _ => Err(AwardError::HandNotShown(seat.player.clone())),
+                }
+            })
+            .collect()
+    }
+
+    /// What each seat should have won, before the rake, splitting the pot
+    /// into side pots and giving odd chips to the first winners left of the
+    /// button, as [`crate::Game`] does.
+    pub fn awards(&self) -> Result<Vec<u64>, AwardError> {
+        let committed = self.committed();
+        let mut awards = vec![0; self.seats.len()];
+        let showdown = self.showdown()?;
+        if showdown.is_empty() {
+            // Everyone else folded. The player left in is whoever acted and
+            // didn't fold, which is the one seat that collected.
+            let winner = (0..self.seats.len())
+                .filter(|&i| self.actions.iter().any(|action| action.seat == i))
+                .find(|&i| {
+                    !self
+                        .actions
+                        .iter()
+                        .any(|action| action.seat == i && action.kind == ActionKind::Fold)
+                });
+            if let Some(winner) = winner {
+                awards[winner] = committed.iter().sum();
+            }
+            return Ok(awards);
+        }
+
+        let mut levels: Vec<u64> = showdown.iter().map(|&(i, _)| committed[i]).collect();
+        levels.sort_unstable();
+        levels.dedup();
+
+        let mut previous = 0;
+        for level in levels {
+            let pot: u64 = committed
+                .iter()
+                .map(|&c| c.min(level) - c.min(previous))
+                .sum();
+            previous = level;
+
+            let eligible: Vec<&(usize, Evaluation)> = showdown
+                .iter()
+                .filter(|&&(i, _)| committed[i] >= level)
+                .collect();
+            let best = eligible.iter().map(|(_, e)| e.strength).max().unwrap_or(0);
+            let n = self.seats.len();
+            let winners: Vec<usize> = (1..=n)
+                .map(|offset| (self.button + offset) % n)
+                .filter(|&i| {
+                    eligible
+                        .iter()
+                        .any(|&&(j, ref e)| j == i && e.strength == best)
+                })
+                .collect();
+
+            let share = pot / winners.len() as u64;
+            let odd_chips = (pot % winners.len() as u64) as usize;
+            for (k, &i) in winners.iter().enumerate() {
+                awards[i] += share + u64::from(k < odd_chips);
+            }
+        }
+        Ok(awards)
+    }
+
+    /// Re-evaluate the showdown and check the pot went where it should.
+    /// With no rake every award must match exactly; with rake each winner
+    /// may have collected less than they won, but nobody who lost may have
+    /// collected anything.
+    pub fn verify(&self) -> Result<(), AwardError> {
+        let awards = self.awards()?;
+        let pot: u64 = awards.iter().sum();
+        let collected: u64 = self.seats.iter().map(|seat| seat.collected).sum();
+        if collected + self.rake != pot {
+            return Err(AwardError::PotMismatch {
+                pot,
+                collected,
+                rake: self.rake,
+            });
+        }
+
+        for (seat, &expected) in self.seats.iter().zip(&awards) {
+            let wrong = match self.rake {
+                0 => seat.collected != expected,
+                _ => seat.collected > expected || (seat.collected == 0) != (expected == 0),
+            };
+            if wrong {
+                return Err(AwardError::WrongAward {
+                    player: seat.player.clone(),
+                    expected,
+                    collected: seat.collected,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+// "$1,234.5", "0.02€" or "1500", in hundredths.
+fn parse_amount(amount: &str) -> Result<u64, HistoryError> {
+    let invalid = || HistoryError::InvalidAmount(amount.to_string());
+    let digits: String = amount
+        .chars()
+        .filter(|c| c.is_ascii_digit() || *c == '.')
+        .collect();
+    let (whole, fraction) = digits.split_once('.').unwrap_or((&digits, ""));
+    if whole.is_empty() && fraction.is_empty() || fraction.len() > 2 || fraction.contains('.') {
+        return Err(invalid());
+    }
+
+    let whole: u64 = match whole {
+        "" => 0,
+        whole => whole.parse().map_err(|_| invalid())?,
+    };
+    let fraction: u64 = format!("{fraction:0<2}").parse().map_err(|_| invalid())?;
+    Ok(whole * 100 + fraction)
+}
+
+// Every card in square brackets on a line, e.g. "*** TURN *** [2c 7d Ks]
+// [9h]". Sites write tens as "T" and suits in lower case.
+fn bracketed_cards(line: &str) -> Result<Vec<Card>, HistoryError> {
+    line.split('[')
+        .skip(1)
+        .filter_map(|group| group.split_once(']'))
+        .flat_map(|(group, _)| group.split_whitespace())
+        .map(|card| {
+            let card = card.to_uppercase();
+            let card = match card.strip_prefix('T') {
+                Some(suit) => format!("10{suit}"),
+                None => card,
+            };
+            card.parse().map_err(HistoryError::InvalidCard)
+        })
+        .collect()
+}
+
+fn hole_cards(text: &str, line: &str) -> Result<[Card; 2], HistoryError> {
+    <[Card; 2]>::try_from(&bracketed_cards(text)?[..])
+        .map_err(|_| HistoryError::UnsupportedGame(line.to_string()))
+}
@@ -0,0 +1,480 @@
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{evaluate, Card, Deck, Evaluation};
+
+/// A betting action. `Raise` gives the total the player's bet for the street
+/// is raised to, and also covers opening bets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Fold,
+    Check,
+    Call,
+    Raise(u32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Street {
+    Preflop,
+    Flop,
+    Turn,
+    River,
+    Showdown,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Seat {
+    pub stack: u32,
+    pub hole: [Card; 2],
+    // Chips put in on the current street, and over the whole hand.
+    pub bet: u32,
+    pub committed: u32,
+    pub folded: bool,
+    acted: bool,
+}
+
+/// Everything one seat is allowed to know when it's their turn to act.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameState {
+    pub seat: usize,
+    pub hole: [Card; 2],
+    pub board: Vec<Card>,
+    pub street: Street,
+    pub button: usize,
+    pub big_blind: u32,
+    pub stacks: Vec<u32>,
+    pub bets: Vec<u32>,
+    pub folded: Vec<bool>,
+    pub pot: u32,
+    pub to_call: u32,
+    pub min_raise_to: u32,
+    pub max_raise_to: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameError {
+    NotEnoughPlayers,
+    EmptyStack(usize),
+    NotEnoughCards,
+    /// The small blind is bigger than the big blind.
+    BlindsOutOfOrder,
+    HandOver,
+    CannotCheck,
+    NothingToCall,
+    RaiseTooSmall {
+        min: u32,
+    },
+    RaiseTooLarge {
+        max: u32,
+    },
+    /// Only an all in for less than a full raise has come since the seat
+    /// last acted, so it can call or fold but not raise.
+    RaisingClosed,
+}
+
+/// A single hand of no-limit Texas Hold'em, from the blinds to the payout.
+#[derive(Debug, Clone)]
+pub struct Game {
+    seats: Vec<Seat>,
+    board: Vec<Card>,
+    deck: Deck,
+    street: Street,
+    button: usize,
+    big_blind: u32,
+    current_bet: u32,
+    min_raise: u32,
+    to_act: Option<usize>,
+    payouts: Option<Vec<u32>>,
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Action::Fold => write!(f, "fold"),
+            Action::Check => write!(f, "check"),
+            Action::Call => write!(f, "call"),
+            Action::Raise(to) => write!(f, "raise to {to}"),
+        }
+    }
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GameError::NotEnoughPlayers => write!(f, "a hand needs at least two players"),
+            GameError::EmptyStack(seat) => write!(f, "seat {seat} has no chips"),
+            GameError::NotEnoughCards => write!(f, "not enough cards in the deck"),
+            GameError::BlindsOutOfOrder => {
+                write!(f, "the small blind is bigger than the big blind")
+            }
+            GameError::HandOver => write!(f, "the hand is over"),
+            GameError::CannotCheck => write!(f, "cannot check facing a bet"),
+            GameError::NothingToCall => write!(f, "there is nothing to call"),
+            GameError::RaiseTooSmall { min } => write!(f, "raise must be to at least {min}"),
+            GameError::RaiseTooLarge { max } => write!(f, "raise can be to at most {max}"),
+            GameError::RaisingClosed => write!(f, "the betting has not been re-opened"),
+        }
+    }
+}
+
+impl std::error::Error for GameError {}
+
+impl Game {
+    /// Deal a new hand and post the blinds. Heads up, the button posts the
+    /// small blind; otherwise the two seats to its left do.
+    pub fn new(
+        stacks: &[u32],
+        button: usize,
+        small_blind: u32,
+        big_blind: u32,
+        mut deck: Deck,
+    ) -> Result<Game, GameError> {
+        if stacks.len() < 2 {
+            return Err(GameError::NotEnoughPlayers);
+        }
+        if let Some(seat) = stacks.iter().position(|&stack| stack == 0) {
+            return Err(GameError::EmptyStack(seat));
+        }
+        // Hole cards, plus five for the board and a burn before each street.
+        if deck.len() < stacks.len() * 2 + 8 {
+            return Err(GameError::NotEnoughCards);
+        }
+        if small_blind > big_blind {
+            return Err(GameError::BlindsOutOfOrder);
+        }
+
+        let seats = stacks
+            .iter()
+            .map(|&stack| {
+                let hole = [deck.deal().unwrap(), deck.deal().unwrap()];
+                Seat {
+                    stack,
+                    hole,
+                    bet: 0,
+                    committed: 0,
+                    folded: false,
+                    acted: false,
+                }
+            })
+            .collect();
+
+        let mut game = Game {
+            seats,
+            board: vec![],
+            deck,
+            street: Street::Preflop,
+            button: button % stacks.len(),
+            big_blind,
+            current_bet: 0,
+            min_raise: big_blind,
+            to_act: None,
+            payouts: None,
+        };
+
+        let (sb, bb) = match stacks.len() {
+            2 => (game.button, game.next_seat(game.button)),
+            _ => {
+                let sb = game.next_seat(game.button);
+                (sb, game.next_seat(sb))
+            }
+        };
+        game.put_in(sb, small_blind);
+        game.put_in(bb, big_blind);
+        game.current_bet = big_blind;
+        game.to_act = Some(bb);
+        game.advance();
+
+        Ok(game)
+    }
+
+    pub fn seats(&self) -> &[Seat] {
+        &self.seats
+    }
+
+    pub fn board(&self) -> &[Card] {
+        &self.board
+    }
+
+    pub fn street(&self) -> Street {
+        self.street
+    }
+
+    pub fn button(&self) -> usize {
+        self.button
+    }
+
+    pub fn big_blind(&self) -> u32 {
+        self.big_blind
+    }
+
+    pub fn current_bet(&self) -> u32 {
+        self.current_bet
+    }
+
+    pub fn pot(&self) -> u32 {
+        self.seats.iter().map(|seat| seat.committed).sum()
+    }
+
+    /// The seat whose turn it is, or `None` once the hand is over.
+    pub fn to_act(&self) -> Option<usize> {
+        self.to_act
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.payouts.is_some()
+    }
+
+    pub fn to_call(&self, seat: usize) -> u32 {
+        let seat = &self.seats[seat];
+        (self.current_bet - seat.bet).min(seat.stack)
+    }
+
+    /// The smallest total a bet can be raised to. A player without that many
+    /// chips can still go all in for less.
+    pub fn min_raise_to(&self) -> u32 {
+        self.current_bet + self.min_raise
+    }
+
+    /// What a seat can put in on this street in all. Facing only a short
+    /// all in since it last acted, that's just a call.
+    pub fn max_raise_to(&self, seat: usize) -> u32 {
+        let seat = &self.seats[seat];
+        let all_in = seat.bet + seat.stack;
+        match seat.acted {
+            true => all_in.min(self.current_bet),
+            false => all_in,
+        }
+    }
+
+    /// Chips won by each seat, once the hand is over. These have already
+    /// been added to the seats' stacks.
+    pub fn payouts(&self) -> Option<&[u32]> {
+        self.payouts.as_deref()
+    }
+
+    /// The game as seen from one seat, hiding everyone else's hole cards.
+    pub fn state(&self, seat: usize) -> GameState {
+        GameState {
+            seat,
+            hole: self.seats[seat].hole,
+            board: self.board.clone(),
+            street: self.street,
+            button: self.button,
+            big_blind: self.big_blind,
+            stacks: self.seats.iter().map(|seat| seat.stack).collect(),
+            bets: self.seats.iter().map(|seat| seat.bet).collect(),
+            folded: self.seats.iter().map(|seat| seat.folded).collect(),
+            pot: self.pot(),
+            to_call: self.to_call(seat),
+            min_raise_to: self.min_raise_to().min(self.max_raise_to(seat)),
+            max_raise_to: self.max_raise_to(seat),
+        }
+    }
+
+    /// The hands shown down, if the hand went to a showdown.
+    pub fn showdown(&self) -> Vec<(usize, Evaluation)> {
+        if self.street != Street::Showdown || self.live_seats().count() < 2 {
+            return vec![];
+        }
+
+        self.live_seats()
+            .filter_map(|i| Some((i, self.evaluate_seat(i)?)))
+            .collect()
+    }
+
+    pub fn apply(&mut self, action: Action) -> Result<(), GameError> {
+        let i = self.to_act.ok_or(GameError::HandOver)?;
+        let to_call = self.to_call(i);
+
+        match action {
+            Action::Fold => self.seats[i].folded = true,
+            Action::Check if to_call > 0 => return Err(GameError::CannotCheck),
+            Action::Check => {}
+            Action::Call if to_call == 0 => return Err(GameError::NothingToCall),
+            Action::Call => self.put_in(i, to_call),
+            Action::Raise(_) if self.seats[i].acted => return Err(GameError::RaisingClosed),
+            Action::Raise(to) => {
+                let max = self.max_raise_to(i);
+                let min = self.min_raise_to().min(max);
+                if to > max {
+                    return Err(GameError::RaiseTooLarge { max });
+                }
+                if to < min || to <= self.current_bet {
+                    return Err(GameError::RaiseTooSmall { min });
+                }
+
+                // An all in for less than a full raise makes everyone put in
+                // the extra, but only re-opens the betting for seats that
+                // haven't acted yet.
+                let full_raise = to - self.current_bet >= self.min_raise;
+                self.min_raise = self.min_raise.max(to - self.current_bet);
+                self.current_bet = to;
+                self.put_in(i, to - self.seats[i].bet);
+                if full_raise {
+                    for seat in self.seats.iter_mut() {
+                        seat.acted = false;
+                    }
+                }
+            }
+        }
+
+        self.seats[i].acted = true;
+        self.advance();
+        Ok(())
+    }
+
+    fn put_in(&mut self, i: usize, amount: u32) {
+        let seat = &mut self.seats[i];
+        let amount = amount.min(seat.stack);
+        seat.stack -= amount;
+        seat.bet += amount;
+        seat.committed += amount;
+    }
+
+    fn next_seat(&self, i: usize) -> usize {
+        (i + 1) % self.seats.len()
+    }
+
+    fn live_seats(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.seats.len()).filter(|&i| !self.seats[i].folded)
+    }
+
+    // Seats that can still make decisions: not folded and not all in.
+    fn can_act(&self, i: usize) -> bool {
+        let seat = &self.seats[i];
+        !seat.folded && seat.stack > 0
+    }
+
+    fn evaluate_seat(&self, i: usize) -> Option<Evaluation> {
+        let cards: Vec<Card> = self.seats[i]
+            .hole
+            .iter()
+            .chain(&self.board)
+            .copied()
+            .collect();
+        evaluate(&cards)
+    }
+
+    // Move on to whoever acts next, dealing the next street or settling the
+    // hand when the betting round is complete.
+    fn advance(&mut self) {
+        if self.live_seats().count() == 1 {
+            return self.settle();
+        }
+
+        let from = self.to_act.unwrap_or(self.button);
+        let next = (1..=self.seats.len())
+            .map(|offset| (from + offset) % self.seats.len())
+            .find(|&i| {
+                let seat = &self.seats[i];
+                self.can_act(i) && (!seat.acted || seat.bet < self.current_bet)
+            });
+
+        // A lone player left with chips has nobody to bet against, unless
+        // they still have a bet to call.
+        let can_act = (0..self.seats.len()).filter(|&i| self.can_act(i)).count();
+        match next {
+            Some(i) if can_act > 1 || self.seats[i].bet < self.current_bet => self.to_act = Some(i),
+            _ => self.next_street(),
+        }
+    }
+
+    fn next_street(&mut self) {
+        for seat in self.seats.iter_mut() {
+            seat.bet = 0;
+            seat.acted = false;
+        }
+        self.current_bet = 0;
+        self.min_raise = self.big_blind;
+
+        let cards = match self.street {
+            Street::Preflop => 3,
+            Street::Flop | Street::Turn => 1,
+            Street::River | Street::Showdown => 0,
+        };
+        if cards > 0 {
+            self.deck.deal();
+            for _ in 0..cards {
+                self.board.extend(self.deck.deal());
+            }
+        }
+        self.street = match self.street {
+            Street::Preflop => Street::Flop,
+            Street::Flop => Street::Turn,
+            Street::Turn => Street::River,
+            Street::River | Street::Showdown => Street::Showdown,
+        };
+
+        if self.street == Street::Showdown {
+            return self.settle();
+        }
+
+        // Postflop the first player left of the button acts first.
+        self.to_act = None;
+        self.advance();
+    }
+
+    // Award the pot, splitting it into side pots by how much each player
+    // committed so that nobody wins more from a player than they put in.
+    fn settle(&mut self) {
+        self.to_act = None;
+        let mut payouts = vec![0; self.seats.len()];
+
+        let live: Vec<usize> = self.live_seats().collect();
+        if let [winner] = live[..] {
+            payouts[winner] = self.pot();
+        } else {
+            let strengths: Vec<u32> = (0..self.seats.len())
+                .map(|i| {
+                    self.evaluate_seat(i)
+                        .map_or(0, |evaluation| evaluation.strength)
+                })
+                .collect();
+
+            let mut levels: Vec<u32> = live.iter().map(|&i| self.seats[i].committed).collect();
+            levels.sort_unstable();
+            levels.dedup();
+
+            let mut previous = 0;
+            for level in levels {
+                let pot: u32 = self
+                    .seats
+                    .iter()
+                    .map(|seat| seat.committed.min(level) - seat.committed.min(previous))
+                    .sum();
+                previous = level;
+
+                let eligible: Vec<usize> = live
+                    .iter()
+                    .copied()
+                    .filter(|&i| self.seats[i].committed >= level)
+                    .collect();
+                let best = eligible.iter().map(|&i| strengths[i]).max().unwrap_or(0);
+                // Winners in order from the left of the button, who get any
+                // odd chips.
+                let winners: Vec<usize> = (1..=self.seats.len())
+                    .map(|offset| (self.button + offset) % self.seats.len())
+                    .filter(|i| eligible.contains(i) && strengths[*i] == best)
+                    .collect();
+
+                let share = pot / winners.len() as u32;
+                let odd_chips = pot as usize % winners.len();
+                for (n, &i) in winners.iter().enumerate() {
+                    payouts[i] += share + u32::from(n < odd_chips);
+                }
+            }
+        }
+
+        for (seat, payout) in self.seats.iter_mut().zip(&payouts) {
+            seat.stack += payout;
+        }
+        self.payouts = Some(payouts);
+    }
+}
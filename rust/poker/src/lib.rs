@@ -1,5 +1,110 @@
-#![feature(iter_array_chunks)]
+#![cfg_attr(not(feature = "std"), no_std)]
 
+use core::cmp::Reverse;
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+mod accumulator;
+mod card;
+mod combinations;
+mod deck;
+mod evaluator;
+mod rng;
+mod suit_order;
+
+// Everything below needs std.
+#[cfg(feature = "std")]
+mod board;
+#[cfg(feature = "std")]
+mod bot;
+#[cfg(feature = "std")]
+mod cfr;
+#[cfg(feature = "std")]
+mod equity;
+#[cfg(feature = "std")]
+mod history;
+#[cfg(feature = "std")]
+mod holdem;
+#[cfg(feature = "std")]
+mod ofc;
+#[cfg(feature = "std")]
+mod pineapple;
+#[cfg(feature = "std")]
+mod preflop;
+#[cfg(feature = "std")]
+mod rake;
+#[cfg(feature = "std")]
+mod server;
+#[cfg(feature = "std")]
+mod showdown;
+#[cfg(feature = "std")]
+mod stats;
+#[cfg(feature = "std")]
+mod three_card;
+#[cfg(feature = "std")]
+mod tournament;
+
+pub use accumulator::Accumulator;
+#[cfg(feature = "std")]
+pub use card::{parse_cards, ParseCardError};
+pub use card::{Card, Rank, Suit};
+pub use combinations::choose;
+#[cfg(feature = "std")]
+pub use combinations::{remaining_cards, runouts, Combinations};
+pub use deck::Composition;
+#[cfg(feature = "std")]
+pub use deck::Deck;
+#[cfg(feature = "std")]
+pub use evaluator::Cached;
+pub use evaluator::{Evaluator, Exhaustive, Incremental};
+pub use rng::Rng;
+pub use suit_order::SuitOrder;
+
+#[cfg(feature = "std")]
+pub use board::{Board, BoardError, HandStrength, Suits, Texture};
+#[cfg(feature = "std")]
+pub use bot::{Bot, CallingStation, RuleBot};
+#[cfg(feature = "std")]
+pub use cfr::{
+    best_response_value, expected_value, exploitability, Cfr, GameTree, Kuhn, KuhnState, Leduc,
+    LeducState, Node, PushFold, PushFoldState, Strategy,
+};
+#[cfg(feature = "std")]
+pub use equity::{equity, Equity, EquityError};
+#[cfg(feature = "std")]
+pub use history::{
+    parse_histories, ActionKind, AwardError, HandHistory, HistoryAction, HistoryError, HistorySeat,
+    Site,
+};
+#[cfg(feature = "std")]
+pub use holdem::{Action, Game, GameError, GameState, Seat, Street};
+#[cfg(feature = "std")]
+pub use ofc::{evaluate_top, score_table, OfcError, OfcHand, OfcScore, TopRow};
+#[cfg(feature = "std")]
+pub use pineapple::{
+    discard_equities, DiscardOption, PineappleDeal, PineappleError, PineappleVariant,
+};
+#[cfg(feature = "std")]
+pub use preflop::{preflop_equity, HeadsUpEquities, PreflopChart, StartingHand};
+#[cfg(feature = "std")]
+pub use rake::{hole_cards_played, BadBeat, BadBeatRule, Rake, RakeReason, RakeRule};
+#[cfg(feature = "std")]
+pub use server::{Client, Message, ParseMessageError, Server, ServerConfig, Turn};
+#[cfg(feature = "std")]
+pub use showdown::{showdown, Showdown, ShowdownError, ShowdownHand};
+#[cfg(feature = "std")]
+pub use stats::{CategoryStats, HandClass, HandDistribution};
+#[cfg(feature = "std")]
+pub use three_card::{
+    ante_house_edge, evaluate_three_card, settle_ante, AnteBonus, PairPlus, ThreeCardHand,
+    ThreeCardRank,
+};
+#[cfg(feature = "std")]
+pub use tournament::{play_match, MatchConfig, MatchResult};
+
+#[cfg(feature = "std")]
 pub fn winning_hands<'a>(hands: &[&'a str]) -> Vec<&'a str> {
     let mut prev_hand = Hand(HandRank::Unknown, 0, 0, 0);
     let mut winners = vec![];
@@ -19,36 +124,229 @@ pub fn winning_hands<'a>(hands: &[&'a str]) -> Vec<&'a str> {
     winners
 }
 
+/// The single best hand, as [`winning_hands`] but with ties broken by suit.
+/// Hands that aren't five valid cards only win if nothing else does.
+#[cfg(feature = "std")]
+pub fn winning_hand<'a>(hands: &[&'a str], order: &SuitOrder) -> Option<&'a str> {
+    let key = |hand: &str| {
+        let cards = parse_cards(hand).ok().filter(|cards| cards.len() == 5)?;
+        let evaluation = evaluate(&cards)?;
+        Some(order.key(evaluation.strength, &evaluation.cards))
+    };
+
+    winning_hands(hands)
+        .into_iter()
+        .reduce(|best, hand| if key(hand) > key(best) { hand } else { best })
+}
+
+/// The result of evaluating a five card hand.
+///
+/// `cards` holds the hand ordered by significance (grouped cards first, then
+/// kickers, with the ace last in a five-high straight), and `strength` packs
+/// the category and that ordering into a single comparable number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Evaluation {
+    pub category: HandRank,
+    pub strength: u32,
+    pub cards: [Card; 5],
+}
+
+/// Evaluate the best five card hand that can be made from `cards`, returning
+/// `None` if there are fewer than five.
+pub fn evaluate(cards: &[Card]) -> Option<Evaluation> {
+    evaluate_with(cards, &Composition::standard())
+}
+
+/// As [`evaluate`], but with the straights and hand order of the given deck.
+pub fn evaluate_with(cards: &[Card], composition: &Composition) -> Option<Evaluation> {
+    // One deck can't deal the same card twice, so any repeats are ignored.
+    let mut distinct = [Card::new(Rank::Two, Suit::Spades); 52];
+    let cards = match composition.deck_count() {
+        1 => {
+            let mut seen = 0u64;
+            let mut len = 0;
+            for &card in cards {
+                let bit = 1 << (card.rank as u64 * 4 + card.suit as u64);
+                if seen & bit == 0 {
+                    seen |= bit;
+                    distinct[len] = card;
+                    len += 1;
+                }
+            }
+            &distinct[..len]
+        }
+        _ => cards,
+    };
+    if cards.len() < 5 {
+        return None;
+    }
+
+    let mut indices = [0, 1, 2, 3, 4];
+    let mut best = Evaluation::from_cards(indices.map(|i| cards[i]), composition);
+    while combinations::next_combination(&mut indices, cards.len()) {
+        best = best.max(Evaluation::from_cards(
+            indices.map(|i| cards[i]),
+            composition,
+        ));
+    }
+
+    Some(best)
+}
+
+impl Evaluation {
+    fn from_cards(mut cards: [Card; 5], composition: &Composition) -> Evaluation {
+        let category = Hand::from_cards(&cards, composition).0;
+
+        // A flush from several decks can hold a pair, but it still ranks
+        // card by card like any other flush.
+        let mut counts = [0; 13];
+        for card in &cards {
+            counts[card.rank as usize] += 1;
+        }
+        let grouped = category != HandRank::Flush;
+        cards.sort_unstable_by_key(|card| {
+            (
+                Reverse(if grouped {
+                    counts[card.rank as usize]
+                } else {
+                    0
+                }),
+                Reverse(card.rank),
+                card.suit,
+            )
+        });
+
+        // The ace plays low when the rest of the straight is the deck's four
+        // lowest ranks, e.g. 6,7,8,9 in a short deck.
+        let rest = cards[1..]
+            .iter()
+            .fold(0, |ranks, card| ranks | 1 << card.rank.index());
+        let is_wheel = matches!(category, HandRank::Straight | HandRank::StraightFlush)
+            && cards[0].rank == Rank::Ace
+            && squeeze_ranks(rest, composition.rank_mask()) == 0b1111;
+        if is_wheel {
+            cards.rotate_left(1);
+        }
+
+        // Four bits for the category, then four per card. Ranks are offset by
+        // one so that an ace playing low can sit beneath the two.
+        let value = composition.category_value(category);
+        let strength = cards.iter().fold(value, |strength, card| {
+            let value = match (is_wheel, card.rank) {
+                (true, Rank::Ace) => 0,
+                (_, rank) => rank as u32 + 1,
+            };
+            strength << 4 | value
+        });
+
+        Evaluation {
+            category,
+            strength,
+            cards,
+        }
+    }
+
+    /// The cards that only count to break a tie between hands of the same
+    /// kind, e.g. the three cards beside a pair. Empty for hands that use
+    /// all five cards, like straights and full houses.
+    pub fn kickers(&self) -> &[Card] {
+        let made = match self.category {
+            HandRank::HighCard => 1,
+            HandRank::OnePair => 2,
+            HandRank::ThreeOfAKind => 3,
+            HandRank::TwoPair | HandRank::FourOfAKind => 4,
+            _ => 5,
+        };
+        &self.cards[made..]
+    }
+
+    /// A human readable description, e.g. "full house, kings over fives".
+    #[cfg(feature = "std")]
+    pub fn description(&self) -> String {
+        let [first, _, _, fourth, fifth] = self.cards.map(|card| card.rank);
+
+        match self.category {
+            HandRank::Unknown => "unknown".to_string(),
+            HandRank::HighCard => format!("high card, {} high", first.name()),
+            HandRank::OnePair => format!("pair of {}", first.plural()),
+            HandRank::TwoPair => format!("two pair, {} and {}", first.plural(), fourth.plural()),
+            HandRank::ThreeOfAKind => format!("three of a kind, {}", first.plural()),
+            HandRank::Straight => format!("straight, {} high", first.name()),
+            HandRank::Flush => format!("flush, {} high", first.name()),
+            HandRank::FullHouse => {
+                format!("full house, {} over {}", first.plural(), fifth.plural())
+            }
+            HandRank::FourOfAKind => format!("four of a kind, {}", first.plural()),
+            HandRank::StraightFlush if first == Rank::Ace => "royal flush".to_string(),
+            HandRank::StraightFlush => format!("straight flush, {} high", first.name()),
+            HandRank::FiveOfAKind => format!("five of a kind, {}", first.plural()),
+            HandRank::FlushFive => format!("flush five, {}", first.plural()),
+        }
+    }
+}
+
+impl PartialOrd for Evaluation {
+    fn partial_cmp(&self, other: &Evaluation) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Evaluation {
+    fn cmp(&self, other: &Evaluation) -> core::cmp::Ordering {
+        self.strength.cmp(&other.strength)
+    }
+}
+
 #[derive(Debug, PartialEq, PartialOrd)]
 struct Hand(HandRank, u16, u16, u16);
 
 impl Hand {
+    #[cfg(feature = "std")]
     fn from_slice(hand_slice: &str) -> Hand {
-        let bfs = hand_slice
-            .chars()
-            .filter_map(parse_valid_hand_char)
-            .array_chunks()
-            .fold(Bitfields::init(), |mut bfs, [rank, suit]| {
-                // Set highest unset bit in the tally.
-                let new_tally_for_rank = bfs.tally_for_rank(rank) << 1 | 1;
-
-                bfs.ranks |= 1 << rank;
-                bfs.suits |= 1 << suit;
-                bfs.tally |= new_tally_for_rank << (rank * 4);
-                bfs.tally_score += 1 << new_tally_for_rank;
-                bfs
-            });
-
-        Hand::from_bitfields(bfs)
-    }
-
-    fn from_bitfields(bfs: Bitfields) -> Hand {
-        match bfs.tally_score {
-            10 => {
-                let is_straight = bfs.is_low_straight() | bfs.is_high_straight();
-                let is_flush = bfs.suits.count_ones() == 1;
-                let kicker = if bfs.is_low_straight() {
-                    0b1111
+        // Take the valid characters two at a time, dropping any left over.
+        let mut values = hand_slice.chars().filter_map(parse_valid_hand_char);
+        let mut bfs = Bitfields::init();
+        // A repeated card can't come from one deck, so it's ignored, which
+        // leaves too few cards to make a hand.
+        let mut seen = 0u64;
+        while let (Some(rank), Some(suit)) = (values.next(), values.next()) {
+            let bit = 1 << (rank * 4 + suit);
+            if seen & bit == 0 {
+                seen |= bit;
+                bfs.add(rank, suit);
+            }
+        }
+
+        Hand::from_bitfields(bfs, ALL_RANKS, false)
+    }
+
+    fn from_cards(cards: &[Card], composition: &Composition) -> Hand {
+        let bfs = cards.iter().fold(Bitfields::init(), |mut bfs, card| {
+            bfs.add(card.rank.index(), card.suit.index());
+            bfs
+        });
+
+        Hand::from_bitfields(bfs, composition.rank_mask(), composition.deck_count() > 1)
+    }
+
+    // `deck_ranks` has a bit set for each rank in the deck, which decides
+    // what counts as a straight. Five of a kind, and flushes holding more
+    // than one card of a rank, can only come from several decks.
+    fn from_bitfields(bfs: Bitfields, deck_ranks: u16, multi_deck: bool) -> Hand {
+        let is_flush = bfs.suits.count_ones() == 1;
+
+        // Each score is the sum of the tallies' highest bits as cards were
+        // added, so with the number of ranks it pins down how many of each
+        // rank there are. Anything that isn't five cards matches nothing.
+        match (bfs.tally_score, bfs.ranks.count_ones()) {
+            (10, 5) => {
+                let is_low_straight = bfs.is_low_straight(deck_ranks);
+                let is_straight = is_low_straight | bfs.is_high_straight(deck_ranks);
+                // With the ace playing low, what's left is lower than any
+                // other straight.
+                let kicker = if is_low_straight {
+                    bfs.zero_out_rank(12)
                 } else {
                     bfs.ranks
                 };
@@ -60,7 +358,11 @@ impl Hand {
                     (true, true) => Hand(HandRank::StraightFlush, kicker, 0, 0),
                 }
             }
-            16 => {
+            // Only possible with more than one deck, and a flush beats them.
+            (16, 4) | (22, 3) | (142, 3) if is_flush && multi_deck => {
+                Hand(HandRank::Flush, bfs.ranks, 0, 0)
+            }
+            (16, 4) => {
                 let (hi, midhi, midlo, lo) = bfs.rankpos4();
                 let (pair, kicker) = match (
                     bfs.tally_for_rank(hi) == 0b11,
@@ -69,14 +371,14 @@ impl Hand {
                 ) {
                     (true, false, false) => (hi, bfs.zero_out_rank(hi)),
                     (false, true, false) => (midhi, bfs.zero_out_rank(midhi)),
-                    (false, false, true) => (midhi, bfs.zero_out_rank(midlo)),
-                    (false, false, false) => (midlo, bfs.zero_out_rank(lo)),
-                    _ => unreachable!(),
+                    (false, false, true) => (midlo, bfs.zero_out_rank(midlo)),
+                    (false, false, false) => (lo, bfs.zero_out_rank(lo)),
+                    _ => return Hand(HandRank::Unknown, 0, 0, 0),
                 };
 
                 Hand(HandRank::OnePair, pair, kicker, 0)
             }
-            22 => {
+            (22, 3) => {
                 let (hi, mid, lo) = bfs.rankpos3();
                 let (high_pair, low_pair, kicker) = match (
                     bfs.tally_for_rank(hi) == 0b11,
@@ -85,21 +387,25 @@ impl Hand {
                     (true, true) => (hi, mid, lo),
                     (true, false) => (hi, lo, mid),
                     (false, true) => (mid, lo, hi),
-                    _ => unreachable!(),
+                    _ => return Hand(HandRank::Unknown, 0, 0, 0),
                 };
 
                 Hand(HandRank::TwoPair, high_pair, low_pair, kicker)
             }
-            142 => {
-                let (hi, lo) = bfs.rankpos2();
-                let (trip, kicker) = match bfs.tally_for_rank(hi) == 0b111 {
-                    true => (hi, bfs.zero_out_rank(hi)),
-                    false => (lo, bfs.zero_out_rank(lo)),
+            (142, 3) => {
+                let (hi, mid, lo) = bfs.rankpos3();
+                let (trip, kicker) = match (
+                    bfs.tally_for_rank(hi) == 0b111,
+                    bfs.tally_for_rank(mid) == 0b111,
+                ) {
+                    (true, _) => (hi, bfs.zero_out_rank(hi)),
+                    (false, true) => (mid, bfs.zero_out_rank(mid)),
+                    (false, false) => (lo, bfs.zero_out_rank(lo)),
                 };
 
                 Hand(HandRank::ThreeOfAKind, trip, kicker, 0)
             }
-            148 => {
+            (148, 2) => {
                 let (hi, lo) = bfs.rankpos2();
                 let (trip, pair) = match bfs.tally_for_rank(hi) == 0b111 {
                     true => (hi, lo),
@@ -108,7 +414,7 @@ impl Hand {
 
                 Hand(HandRank::FullHouse, trip, pair, 0)
             }
-            32908 => {
+            (32908, 2) => {
                 let (hi, lo) = bfs.rankpos2();
                 let (quad, kicker) = match bfs.tally_for_rank(hi) == 0b1111 {
                     true => (hi, bfs.zero_out_rank(hi)),
@@ -117,6 +423,13 @@ impl Hand {
 
                 Hand(HandRank::FourOfAKind, quad, kicker, 0)
             }
+            (2147516554, 1) if multi_deck => {
+                let rank = u16_trailing_zeros(bfs.ranks);
+                match is_flush {
+                    true => Hand(HandRank::FlushFive, rank, 0, 0),
+                    false => Hand(HandRank::FiveOfAKind, rank, 0, 0),
+                }
+            }
             _ => Hand(HandRank::Unknown, 0, 0, 0),
         }
     }
@@ -127,8 +440,13 @@ fn u16_trailing_zeros(n: u16) -> u16 {
     u16::try_from(n.trailing_zeros()).unwrap()
 }
 
-#[derive(Debug, Clone, Copy, Eq, Ord, PartialOrd, PartialEq)]
-enum HandRank {
+#[derive(Debug, Clone, Copy, Eq, Hash, Ord, PartialOrd, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum HandRank {
     Unknown,
     HighCard,
     OnePair,
@@ -139,11 +457,38 @@ enum HandRank {
     FullHouse,
     FourOfAKind,
     StraightFlush,
+    // Only possible with more than one deck.
+    FiveOfAKind,
+    FlushFive,
+}
+
+impl fmt::Display for HandRank {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            HandRank::Unknown => "unknown",
+            HandRank::HighCard => "high card",
+            HandRank::OnePair => "one pair",
+            HandRank::TwoPair => "two pair",
+            HandRank::ThreeOfAKind => "three of a kind",
+            HandRank::Straight => "straight",
+            HandRank::Flush => "flush",
+            HandRank::FullHouse => "full house",
+            HandRank::FourOfAKind => "four of a kind",
+            HandRank::StraightFlush => "straight flush",
+            HandRank::FiveOfAKind => "five of a kind",
+            HandRank::FlushFive => "flush five",
+        };
+        f.write_str(s)
+    }
 }
 
-#[derive(Debug)]
+// Every rank from two to ace.
+const ALL_RANKS: u16 = 0b1_1111_1111_1111;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 struct Bitfields {
-    tally: u64,
+    // Five bits per rank, so that several decks can make five of a kind.
+    tally: u128,
     tally_score: u32,
     ranks: u16,
     suits: u16,
@@ -159,17 +504,36 @@ impl Bitfields {
         }
     }
 
+    fn add(&mut self, rank: u16, suit: u16) {
+        // Set highest unset bit in the tally. Past five of a rank the tally
+        // is full, and the saturated score won't match any hand.
+        let new_tally_for_rank = (self.tally_for_rank(rank) << 1 | 1) & 0b11111;
+
+        self.ranks |= 1 << rank;
+        self.suits |= 1 << suit;
+        self.tally |= new_tally_for_rank << (rank * 5);
+        self.tally_score = self.tally_score.saturating_add(1 << new_tally_for_rank);
+    }
+
     // A sequence of 5 contiguous set bits in the ranks represents a straight.
-    fn is_high_straight(&self) -> bool {
+    // Ranks missing from the deck are skipped over, so in a deck without tens
+    // 8,9,J,Q,K is a straight.
+    fn is_high_straight(&self, deck_ranks: u16) -> bool {
+        let ranks = squeeze_ranks(self.ranks, deck_ranks);
         let high_mask = 0b11111;
-        (self.ranks >> self.ranks.trailing_zeros() & high_mask) == high_mask
+        (ranks >> ranks.trailing_zeros() & high_mask) == high_mask
     }
 
-    // Aces occupy the highest bit set in ranks. A straight can be A,2,3,4,5, so
-    // in that case, looking for 5 contiguous bits won't work.
-    fn is_low_straight(&self) -> bool {
-        let low_mask = 0b0001000000001111;
-        (self.ranks & low_mask) == low_mask
+    // Aces occupy the highest bit set in ranks. A straight can be A,2,3,4,5 (or
+    // A,6,7,8,9 with the low cards stripped out), so in that case, looking for
+    // 5 contiguous bits won't work.
+    fn is_low_straight(&self, deck_ranks: u16) -> bool {
+        if deck_ranks >> 12 & 1 == 0 {
+            return false;
+        }
+        let ranks = squeeze_ranks(self.ranks, deck_ranks);
+        let low_mask = 1 << (deck_ranks.count_ones() - 1) | 0b1111;
+        (ranks & low_mask) == low_mask
     }
 
     // If there are two ranks present, get lowest and highest positions
@@ -206,9 +570,9 @@ impl Bitfields {
         (hirank, midhirank, midlorank, lorank)
     }
 
-    // Isolate the four bits representing a tally for a given card rank
-    fn tally_for_rank(&self, rank: u16) -> u64 {
-        self.tally >> (rank * 4) & 0b1111
+    // Isolate the five bits representing a tally for a given card rank
+    fn tally_for_rank(&self, rank: u16) -> u128 {
+        self.tally >> (rank * 5) & 0b11111
     }
 
     // Return a new set of ranks with given rank zeroed out
@@ -217,7 +581,19 @@ impl Bitfields {
     }
 }
 
+// Close up the gaps left in `ranks` by ranks missing from the deck.
+fn squeeze_ranks(ranks: u16, deck_ranks: u16) -> u16 {
+    if deck_ranks == ALL_RANKS {
+        return ranks;
+    }
+    (0..13)
+        .filter(|rank| deck_ranks >> rank & 1 == 1)
+        .enumerate()
+        .fold(0, |squeezed, (i, rank)| squeezed | (ranks >> rank & 1) << i)
+}
+
 // Parse
+#[cfg(feature = "std")]
 fn parse_valid_hand_char(c: char) -> Option<u16> {
     match c {
         '2'..='9' => Some((c as u16) - 50),
@@ -0,0 +1,143 @@
+use std::collections::HashSet;
+use std::io::{self, BufRead};
+
+use anyhow::{bail, Context, Result};
+use poker::{equity, evaluate, parse_cards, winning_hands, Card};
+
+const USAGE: &str = "usage: poker [--board CARDS] [--dead CARDS] [HAND ...]
+
+Hands are given as arguments, or one per line on stdin if there are none.
+Without --board or --dead each hand must be five cards, and the winners are
+printed. With either option each hand is a set of hole cards, and the equity
+of each against the others is printed as well.";
+
+#[derive(Default)]
+struct Options {
+    board: Option<Vec<Card>>,
+    dead: Vec<Card>,
+    hands: Vec<String>,
+}
+
+fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let mut options = parse_args(&args)?;
+
+    if options.hands.is_empty() {
+        for line in io::stdin().lock().lines() {
+            let line = line.context("failed to read stdin")?;
+            if !line.trim().is_empty() {
+                options.hands.push(line.trim().to_string());
+            }
+        }
+    }
+    if options.hands.is_empty() {
+        bail!("no hands given\n\n{USAGE}");
+    }
+
+    match options.board.is_some() || !options.dead.is_empty() {
+        true => print_equity(&options),
+        false => print_showdown(&options.hands),
+    }
+}
+
+fn parse_args(mut args: &[String]) -> Result<Options> {
+    let mut options = Options::default();
+
+    loop {
+        match args {
+            [] => return Ok(options),
+            [flag, ..] if flag == "-h" || flag == "--help" => {
+                println!("{USAGE}");
+                std::process::exit(0);
+            }
+            [flag, value, rest @ ..] if flag == "--board" => {
+                options.board = Some(parse_hand(value)?);
+                args = rest;
+            }
+            [flag, value, rest @ ..] if flag == "--dead" => {
+                options.dead = parse_hand(value)?;
+                args = rest;
+            }
+            [flag, ..] if flag.starts_with("--") => {
+                bail!("unknown or incomplete option {flag}\n\n{USAGE}")
+            }
+            [hand, rest @ ..] => {
+                options.hands.push(hand.clone());
+                args = rest;
+            }
+        }
+    }
+}
+
+fn parse_hand(hand: &str) -> Result<Vec<Card>> {
+    let cards = parse_cards(hand).with_context(|| format!("malformed hand {hand:?}"))?;
+
+    let mut seen = HashSet::new();
+    if let Some(card) = cards.iter().find(|&&card| !seen.insert(card)) {
+        bail!("{card} appears twice in {hand:?}");
+    }
+
+    Ok(cards)
+}
+
+fn print_showdown(hands: &[String]) -> Result<()> {
+    for hand in hands {
+        let cards = parse_hand(hand)?;
+        if cards.len() != 5 {
+            bail!("{hand:?} has {} cards, expected 5", cards.len());
+        }
+
+        let evaluation = evaluate(&cards).context("evaluation needs five cards")?;
+        println!(
+            "{hand}: {} (strength {:#x})",
+            evaluation.description(),
+            evaluation.strength
+        );
+    }
+
+    let hands: Vec<&str> = hands.iter().map(String::as_str).collect();
+    for winner in winning_hands(&hands) {
+        println!("winner: {winner}");
+    }
+
+    Ok(())
+}
+
+fn print_equity(options: &Options) -> Result<()> {
+    let board = options.board.clone().unwrap_or_default();
+    let holdings = options
+        .hands
+        .iter()
+        .map(|hand| parse_hand(hand))
+        .collect::<Result<Vec<_>>>()?;
+
+    let equities = equity(&holdings, &board, &options.dead)?;
+
+    for ((hand, holding), equity) in options.hands.iter().zip(&holdings).zip(&equities) {
+        let cards: Vec<Card> = holding.iter().chain(&board).copied().collect();
+        let made = match evaluate(&cards) {
+            Some(evaluation) => format!(
+                ", {} (strength {:#x})",
+                evaluation.description(),
+                evaluation.strength
+            ),
+            None => String::new(),
+        };
+        println!(
+            "{hand}: equity {:.2}% (win {:.2}%, tie {:.2}%){made}",
+            equity.equity * 100.0,
+            equity.win * 100.0,
+            equity.tie * 100.0
+        );
+    }
+
+    if board.len() == 5 {
+        for (hand, equity) in options.hands.iter().zip(&equities) {
+            if equity.equity > 0.0 {
+                println!("winner: {hand}");
+            }
+        }
+    }
+
+    Ok(())
+}
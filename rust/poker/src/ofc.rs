@@ -0,0 +1,224 @@
+use std::cmp::{Ordering, Reverse};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{evaluate, parse_cards, Card, Evaluation, HandRank, ParseCardError, Rank};
+
+/// The three card top row of an open-face hand. Only high card, one pair and
+/// three of a kind are possible.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TopRow {
+    pub category: HandRank,
+    /// Packed like [`Evaluation::strength`], with the two missing cards
+    /// counting as nothing, so the two can be compared directly. A pair of
+    /// kings with an ace loses to any pair of kings with an ace and more.
+    pub strength: u32,
+    pub cards: [Card; 3],
+}
+
+/// Evaluate a three card top row.
+pub fn evaluate_top(mut cards: [Card; 3]) -> TopRow {
+    let mut counts = [0; 13];
+    for card in &cards {
+        counts[card.rank as usize] += 1;
+    }
+    cards.sort_by_key(|card| {
+        (
+            Reverse(counts[card.rank as usize]),
+            Reverse(card.rank),
+            card.suit,
+        )
+    });
+
+    let category = match counts.iter().max() {
+        Some(3) => HandRank::ThreeOfAKind,
+        Some(2) => HandRank::OnePair,
+        _ => HandRank::HighCard,
+    };
+    let strength = cards.iter().fold(category as u32, |strength, card| {
+        strength << 4 | (card.rank as u32 + 1)
+    }) << 8;
+
+    TopRow {
+        category,
+        strength,
+        cards,
+    }
+}
+
+/// A set open-face hand: three cards on top, five in the middle and five on
+/// the bottom.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OfcHand {
+    pub top: TopRow,
+    pub middle: Evaluation,
+    pub bottom: Evaluation,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OfcError {
+    /// The rows, top first, weren't 3, 5 and 5 cards.
+    WrongRowSizes(Vec<usize>),
+    DuplicateCard(Card),
+    InvalidCard(ParseCardError),
+}
+
+impl fmt::Display for OfcError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OfcError::WrongRowSizes(sizes) => {
+                write!(f, "rows must be 3, 5 and 5 cards, not {sizes:?}")
+            }
+            OfcError::DuplicateCard(card) => write!(f, "{card} appears more than once"),
+            OfcError::InvalidCard(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for OfcError {}
+
+/// One player's result against another, from their side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct OfcScore {
+    /// One point for each row won, less one for each row lost.
+    pub rows: i32,
+    /// Three more for winning all three rows, or three less for losing them.
+    pub scoop: i32,
+    /// Our royalties less theirs.
+    pub royalties: i32,
+}
+
+impl OfcScore {
+    pub fn total(&self) -> i32 {
+        self.rows + self.scoop + self.royalties
+    }
+}
+
+impl OfcHand {
+    pub fn new(top: [Card; 3], middle: [Card; 5], bottom: [Card; 5]) -> Result<OfcHand, OfcError> {
+        let cards: Vec<Card> = top.iter().chain(&middle).chain(&bottom).copied().collect();
+        for (i, card) in cards.iter().enumerate() {
+            if cards[..i].contains(card) {
+                return Err(OfcError::DuplicateCard(*card));
+            }
+        }
+
+        Ok(OfcHand {
+            top: evaluate_top(top),
+            middle: evaluate(&middle).expect("a row of five cards"),
+            bottom: evaluate(&bottom).expect("a row of five cards"),
+        })
+    }
+
+    /// A hand is fouled unless each row is at least as strong as the one
+    /// above it. A fouled hand loses every row and scores no royalties.
+    pub fn is_fouled(&self) -> bool {
+        self.top.strength > self.middle.strength || self.middle > self.bottom
+    }
+
+    /// Bonus points for strong rows, on the usual American table.
+    pub fn royalties(&self) -> u32 {
+        if self.is_fouled() {
+            return 0;
+        }
+        top_royalty(&self.top) + middle_royalty(&self.middle) + bottom_royalty(&self.bottom)
+    }
+
+    /// Score this hand against another.
+    pub fn score(&self, other: &OfcHand) -> OfcScore {
+        let royalties = self.royalties() as i32 - other.royalties() as i32;
+        let rows = match (self.is_fouled(), other.is_fouled()) {
+            (true, true) => return OfcScore::default(),
+            (true, false) => -3,
+            (false, true) => 3,
+            (false, false) => [
+                self.top.strength.cmp(&other.top.strength),
+                self.middle.cmp(&other.middle),
+                self.bottom.cmp(&other.bottom),
+            ]
+            .iter()
+            .map(|ordering| match ordering {
+                Ordering::Less => -1,
+                Ordering::Equal => 0,
+                Ordering::Greater => 1,
+            })
+            .sum(),
+        };
+        let scoop = match rows {
+            3 => 3,
+            -3 => -3,
+            _ => 0,
+        };
+
+        OfcScore {
+            rows,
+            scoop,
+            royalties,
+        }
+    }
+}
+
+/// Everyone's net points when each hand is scored against every other.
+pub fn score_table(hands: &[OfcHand]) -> Vec<i32> {
+    hands
+        .iter()
+        .map(|hand| hands.iter().map(|other| hand.score(other).total()).sum())
+        .collect()
+}
+
+fn top_royalty(top: &TopRow) -> u32 {
+    let rank = top.cards[0].rank as u32;
+    match top.category {
+        // 222 scores 10, up to 22 for AAA.
+        HandRank::ThreeOfAKind => rank + 10,
+        // 66 scores 1, up to 9 for AA.
+        HandRank::OnePair if top.cards[0].rank >= Rank::Six => rank - 3,
+        _ => 0,
+    }
+}
+
+// Double the bottom row's, plus two for trips.
+fn middle_royalty(middle: &Evaluation) -> u32 {
+    match middle.category {
+        HandRank::ThreeOfAKind => 2,
+        _ => bottom_royalty(middle) * 2,
+    }
+}
+
+fn bottom_royalty(bottom: &Evaluation) -> u32 {
+    match bottom.category {
+        HandRank::Straight => 2,
+        HandRank::Flush => 4,
+        HandRank::FullHouse => 6,
+        HandRank::FourOfAKind => 10,
+        HandRank::StraightFlush if bottom.cards[0].rank == Rank::Ace => 25,
+        HandRank::StraightFlush => 15,
+        _ => 0,
+    }
+}
+
+impl FromStr for OfcHand {
+    type Err = OfcError;
+
+    /// Rows top first, separated by slashes, e.g. "QS QH 2D / ... / ...".
+    fn from_str(s: &str) -> Result<OfcHand, OfcError> {
+        let rows = s
+            .split('/')
+            .map(parse_cards)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(OfcError::InvalidCard)?;
+        let sizes: Vec<usize> = rows.iter().map(Vec::len).collect();
+
+        match &rows[..] {
+            [top, middle, bottom] => match (
+                <[Card; 3]>::try_from(&top[..]),
+                <[Card; 5]>::try_from(&middle[..]),
+                <[Card; 5]>::try_from(&bottom[..]),
+            ) {
+                (Ok(top), Ok(middle), Ok(bottom)) => OfcHand::new(top, middle, bottom),
+                _ => Err(OfcError::WrongRowSizes(sizes)),
+            },
+            _ => Err(OfcError::WrongRowSizes(sizes)),
+        }
+    }
+}
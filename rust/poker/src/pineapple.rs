@@ -0,0 +1,217 @@
+use std::fmt;
+
+use crate::{equity, showdown, Card, Deck, Equity, EquityError, Showdown, ShowdownError, Street};
+
+/// When each player has to throw away their third hole card.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PineappleVariant {
+    /// Before the flop.
+    Classic,
+    /// After the flop, before the turn.
+    Crazy,
+}
+
+impl PineappleVariant {
+    // The first street that can't be dealt until everyone has discarded.
+    fn discard_by(self) -> Street {
+        match self {
+            PineappleVariant::Classic => Street::Flop,
+            PineappleVariant::Crazy => Street::Turn,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PineappleError {
+    NotEnoughPlayers,
+    NotEnoughCards,
+    /// Crazy Pineapple discards wait for the flop.
+    TooEarly,
+    AlreadyDiscarded(usize),
+    NotInHand {
+        seat: usize,
+        card: Card,
+    },
+    /// The seats that still have to discard before the next street.
+    WaitingForDiscards(Vec<usize>),
+    HandOver,
+}
+
+impl fmt::Display for PineappleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PineappleError::NotEnoughPlayers => write!(f, "a hand needs at least two players"),
+            PineappleError::NotEnoughCards => write!(f, "not enough cards in the deck"),
+            PineappleError::TooEarly => write!(f, "cannot discard before the flop"),
+            PineappleError::AlreadyDiscarded(seat) => {
+                write!(f, "seat {seat} has already discarded")
+            }
+            PineappleError::NotInHand { seat, card } => {
+                write!(f, "seat {seat} does not hold {card}")
+            }
+            PineappleError::WaitingForDiscards(seats) => {
+                write!(f, "seats {seats:?} have to discard first")
+            }
+            PineappleError::HandOver => write!(f, "the board is complete"),
+        }
+    }
+}
+
+impl std::error::Error for PineappleError {}
+
+/// The cards of a hand of Pineapple: three hole cards each, one of which is
+/// thrown away, then a hold'em board. Betting is left to the caller.
+#[derive(Debug, Clone)]
+pub struct PineappleDeal {
+    variant: PineappleVariant,
+    deck: Deck,
+    hands: Vec<Vec<Card>>,
+    discards: Vec<Option<Card>>,
+    board: Vec<Card>,
+}
+
+impl PineappleDeal {
+    pub fn new(
+        variant: PineappleVariant,
+        players: usize,
+        mut deck: Deck,
+    ) -> Result<PineappleDeal, PineappleError> {
+        if players < 2 {
+            return Err(PineappleError::NotEnoughPlayers);
+        }
+        // Hole cards, plus five for the board and a burn before each street.
+        if deck.len() < players * 3 + 8 {
+            return Err(PineappleError::NotEnoughCards);
+        }
+
+        let hands = (0..players)
+            .map(|_| (0..3).map(|_| deck.deal().unwrap()).collect())
+            .collect();
+        Ok(PineappleDeal {
+            variant,
+            deck,
+            hands,
+            discards: vec![None; players],
+            board: vec![],
+        })
+    }
+
+    pub fn variant(&self) -> PineappleVariant {
+        self.variant
+    }
+
+    /// A seat's hole cards: three until they discard, then two.
+    pub fn hand(&self, seat: usize) -> &[Card] {
+        &self.hands[seat]
+    }
+
+    pub fn discarded(&self, seat: usize) -> Option<Card> {
+        self.discards[seat]
+    }
+
+    pub fn board(&self) -> &[Card] {
+        &self.board
+    }
+
+    pub fn street(&self) -> Street {
+        match self.board.len() {
+            0 => Street::Preflop,
+            3 => Street::Flop,
+            4 => Street::Turn,
+            _ => Street::River,
+        }
+    }
+
+    pub fn discard(&mut self, seat: usize, card: Card) -> Result<(), PineappleError> {
+        if self.variant == PineappleVariant::Crazy && self.board.is_empty() {
+            return Err(PineappleError::TooEarly);
+        }
+        if self.discards[seat].is_some() {
+            return Err(PineappleError::AlreadyDiscarded(seat));
+        }
+        let i = self.hands[seat]
+            .iter()
+            .position(|&c| c == card)
+            .ok_or(PineappleError::NotInHand { seat, card })?;
+
+        self.hands[seat].remove(i);
+        self.discards[seat] = Some(card);
+        Ok(())
+    }
+
+    /// Burn a card and deal the next street, once everyone who has to has
+    /// discarded.
+    pub fn deal_street(&mut self) -> Result<&[Card], PineappleError> {
+        let (street, cards) = match self.board.len() {
+            0 => (Street::Flop, 3),
+            3 => (Street::Turn, 1),
+            4 => (Street::River, 1),
+            _ => return Err(PineappleError::HandOver),
+        };
+        if street >= self.variant.discard_by() {
+            let waiting: Vec<usize> = (0..self.hands.len())
+                .filter(|&seat| self.discards[seat].is_none())
+                .collect();
+            if !waiting.is_empty() {
+                return Err(PineappleError::WaitingForDiscards(waiting));
+            }
+        }
+
+        self.deck.deal();
+        for _ in 0..cards {
+            self.board.extend(self.deck.deal());
+        }
+        Ok(&self.board)
+    }
+
+    /// Every seat's hand with the board as it stands.
+    pub fn showdown(&self) -> Result<Showdown, ShowdownError> {
+        let hands: Vec<Vec<Card>> = self
+            .hands
+            .iter()
+            .map(|hand| hand.iter().chain(&self.board).copied().collect())
+            .collect();
+        showdown(&hands)
+    }
+}
+
+/// One way to play a three card hand, and how it fares.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DiscardOption {
+    pub discard: Card,
+    pub kept: [Card; 2],
+    pub equity: Equity,
+}
+
+/// The exact equity of keeping each two of a three card hand against the
+/// other players' hands as they'll be at showdown, best first. The card
+/// thrown away is dead, along with `dead`, which can include the other
+/// players' discards.
+pub fn discard_equities(
+    hand: [Card; 3],
+    opponents: &[Vec<Card>],
+    board: &[Card],
+    dead: &[Card],
+) -> Result<Vec<DiscardOption>, EquityError> {
+    let mut options = (0..3)
+        .map(|i| {
+            let discard = hand[i];
+            let kept = [hand[(i + 1) % 3], hand[(i + 2) % 3]];
+
+            let mut holdings = vec![kept.to_vec()];
+            holdings.extend(opponents.iter().cloned());
+            let mut dead = dead.to_vec();
+            dead.push(discard);
+
+            let equity = equity(&holdings, board, &dead)?[0];
+            Ok(DiscardOption {
+                discard,
+                kept,
+                equity,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    options.sort_by(|a, b| b.equity.equity.total_cmp(&a.equity.equity));
+    Ok(options)
+}
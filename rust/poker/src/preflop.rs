@@ -0,0 +1,397 @@
+use std::fmt;
+use std::thread;
+
+use crate::stats::best_strength;
+use crate::{evaluate, Card, Rank, Rng, Suit};
+
+/// One of the 169 distinct two card starting hands, ignoring suits beyond
+/// whether the two cards share one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StartingHand {
+    pub high: Rank,
+    pub low: Rank,
+    pub suited: bool,
+}
+
+impl StartingHand {
+    /// All 169 starting hands in chart order: row by row from aces down,
+    /// with pairs on the diagonal, suited hands above it and offsuit below.
+    pub fn all() -> Vec<StartingHand> {
+        let ranks = Rank::ALL.iter().rev();
+        ranks
+            .clone()
+            .flat_map(|&row| ranks.clone().map(move |&col| StartingHand::at(row, col)))
+            .collect()
+    }
+
+    // The hand at a row and column of the chart.
+    pub(crate) fn at(row: Rank, col: Rank) -> StartingHand {
+        StartingHand {
+            high: row.max(col),
+            low: row.min(col),
+            suited: row > col,
+        }
+    }
+
+    pub fn is_pair(&self) -> bool {
+        self.high == self.low
+    }
+
+    /// How many ways the hand can be dealt.
+    pub fn combos(&self) -> usize {
+        match (self.is_pair(), self.suited) {
+            (true, _) => 6,
+            (false, true) => 4,
+            (false, false) => 12,
+        }
+    }
+
+    /// Every way of holding the hand.
+    pub fn holdings(&self) -> Vec<[Card; 2]> {
+        let mut holdings = vec![];
+        for (i, &first) in Suit::ALL.iter().enumerate() {
+            for &second in &Suit::ALL[i..] {
+                let holding = [Card::new(self.high, first), Card::new(self.low, second)];
+                let fits = match (self.is_pair(), self.suited) {
+                    (true, _) => first != second,
+                    (false, true) => first == second,
+                    (false, false) => first != second,
+                };
+                if fits {
+                    holdings.push(holding);
+                    // Offsuit hands can have the suits either way round.
+                    if !self.is_pair() && !self.suited {
+                        holdings.push([Card::new(self.high, second), Card::new(self.low, first)]);
+                    }
+                }
+            }
+        }
+        holdings
+    }
+
+    /// Every way of holding the hand that doesn't use a blocked card, e.g.
+    /// one of the board or another player's hole cards.
+    pub fn live_holdings(&self, blockers: &[Card]) -> Vec<[Card; 2]> {
+        self.holdings()
+            .into_iter()
+            .filter(|holding| !holding.iter().any(|card| blockers.contains(card)))
+            .collect()
+    }
+
+    /// How many ways the hand can be dealt around the blockers.
+    pub fn live_combos(&self, blockers: &[Card]) -> usize {
+        self.live_holdings(blockers).len()
+    }
+
+    /// One concrete way of holding the hand.
+    pub fn cards(&self) -> [Card; 2] {
+        let second_suit = if self.suited {
+            Suit::Spades
+        } else {
+            Suit::Hearts
+        };
+        [
+            Card::new(self.high, Suit::Spades),
+            Card::new(self.low, second_suit),
+        ]
+    }
+}
+
+// Charts conventionally use a single character for every rank.
+pub(crate) fn rank_char(rank: Rank) -> char {
+    match rank {
+        Rank::Ten => 'T',
+        _ => rank.to_string().chars().next().unwrap_or('?'),
+    }
+}
+
+impl fmt::Display for StartingHand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", rank_char(self.high), rank_char(self.low))?;
+        match (self.is_pair(), self.suited) {
+            (true, _) => Ok(()),
+            (false, true) => write!(f, "s"),
+            (false, false) => write!(f, "o"),
+        }
+    }
+}
+
+/// Monte Carlo estimate of a hand's all-in equity against `opponents`
+/// random hands, over `trials` random deals. `None` without any opponents
+/// or trials, or with more opponents than the deck can deal to.
+pub fn preflop_equity(
+    hand: StartingHand,
+    opponents: usize,
+    trials: usize,
+    rng: &mut Rng,
+) -> Option<f64> {
+    let hole = hand.cards();
+    let mut deck: Vec<Card> = Card::all().filter(|card| !hole.contains(card)).collect();
+    let needed = opponents * 2 + 5;
+    if opponents == 0 || trials == 0 || needed > deck.len() {
+        return None;
+    }
+
+    let mut share = 0.0;
+    let mut cards = Vec::with_capacity(7);
+    for _ in 0..trials {
+        // A partial shuffle of just the cards needed.
+        for i in 0..needed {
+            let j = i + rng.below(deck.len() - i);
+            deck.swap(i, j);
+        }
+        let (dealt, board) = deck[..needed].split_at(opponents * 2);
+
+        let mut strength = |hole: &[Card]| {
+            cards.clear();
+            cards.extend_from_slice(hole);
+            cards.extend_from_slice(board);
+            evaluate(&cards).map_or(0, |evaluation| evaluation.strength)
+        };
+        let hero = strength(&hole);
+        let mut ties = 1;
+        let mut beaten = false;
+        for opponent in dealt.chunks(2) {
+            let villain = strength(opponent);
+            if villain > hero {
+                beaten = true;
+                break;
+            }
+            ties += usize::from(villain == hero);
+        }
+        if !beaten {
+            share += 1.0 / ties as f64;
+        }
+    }
+
+    Some(share / trials as f64)
+}
+
+/// Equity of every starting hand against one to `max_opponents` random
+/// hands. Each cell is simulated from its own seed, so a chart can be
+/// rebuilt exactly from its seed and trial count alone.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PreflopChart {
+    pub hands: Vec<StartingHand>,
+    pub max_opponents: usize,
+    pub trials: usize,
+    pub seed: u64,
+    // Indexed by hand, then by number of opponents less one.
+    equities: Vec<Vec<f64>>,
+}
+
+impl PreflopChart {
+    /// `None` unless `max_opponents` is one to nine and there's at least one
+    /// trial.
+    pub fn generate(max_opponents: usize, trials: usize, seed: u64) -> Option<PreflopChart> {
+        if !(1..=9).contains(&max_opponents) || trials == 0 {
+            return None;
+        }
+        let hands = StartingHand::all();
+        let threads = thread::available_parallelism().map_or(1, |n| n.get());
+        let chunk = hands.len().div_ceil(threads);
+
+        let equities = thread::scope(|scope| {
+            let workers: Vec<_> = hands
+                .chunks(chunk)
+                .enumerate()
+                .map(|(n, slice)| {
+                    scope.spawn(move || {
+                        slice
+                            .iter()
+                            .enumerate()
+                            .map(|(i, &hand)| {
+                                let index = (n * chunk + i) as u64;
+                                (1..=max_opponents)
+                                    .map(|opponents| {
+                                        let cell =
+                                            index * (max_opponents as u64 + 1) + opponents as u64;
+                                        let mut rng =
+                                            Rng::new(seed ^ cell.wrapping_mul(0x9e3779b97f4a7c15));
+                                        preflop_equity(hand, opponents, trials, &mut rng)
+                                            .expect("checked above")
+                                    })
+                                    .collect::<Vec<f64>>()
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+            workers
+                .into_iter()
+                .flat_map(|worker| worker.join().expect("chart worker panicked"))
+                .collect()
+        });
+
+        Some(PreflopChart {
+            hands,
+            max_opponents,
+            trials,
+            seed,
+            equities,
+        })
+    }
+
+    pub fn equity(&self, hand: StartingHand, opponents: usize) -> Option<f64> {
+        let index = self.hands.iter().position(|&h| h == hand)?;
+        self.equities[index].get(opponents.checked_sub(1)?).copied()
+    }
+
+    /// One row per hand: its name, number of combos, then its equity against
+    /// each number of opponents.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("hand,combos");
+        for opponents in 1..=self.max_opponents {
+            csv += &format!(",vs{opponents}");
+        }
+        csv.push('\n');
+
+        for (hand, equities) in self.hands.iter().zip(&self.equities) {
+            csv += &format!("{hand},{}", hand.combos());
+            for equity in equities {
+                csv += &format!(",{equity:.4}");
+            }
+            csv.push('\n');
+        }
+
+        csv
+    }
+
+    /// The familiar 13x13 grid of equities (as percentages) against a given
+    /// number of opponents.
+    pub fn ascii_grid(&self, opponents: usize) -> String {
+        let ranks: Vec<Rank> = Rank::ALL.iter().rev().copied().collect();
+        let mut grid = String::from("    ");
+        for &rank in &ranks {
+            grid += &format!("{:>5}", rank_char(rank));
+        }
+        grid.push('\n');
+
+        for &row in &ranks {
+            grid += &format!("{:>4}", rank_char(row));
+            for &col in &ranks {
+                match self.equity(StartingHand::at(row, col), opponents) {
+                    Some(equity) => grid += &format!("{:>5.1}", equity * 100.0),
+                    None => grid += "    -",
+                }
+            }
+            grid.push('\n');
+        }
+
+        grid
+    }
+}
+
+/// All-in equity of every starting hand against every other heads up, with
+/// how often each pairing is dealt.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeadsUpEquities {
+    pub hands: Vec<StartingHand>,
+    pub trials: usize,
+    pub seed: u64,
+    // Both indexed by hand, then by opponent's hand.
+    equities: Vec<Vec<f64>>,
+    deals: Vec<Vec<u32>>,
+}
+
+impl HeadsUpEquities {
+    /// Estimate each pairing's equity from `trials` random deals of both
+    /// hands and a board. Like [`PreflopChart`], every pairing has its own
+    /// seed. `None` without any trials.
+    pub fn estimate(trials: usize, seed: u64) -> Option<HeadsUpEquities> {
+        if trials == 0 {
+            return None;
+        }
+        let hands = StartingHand::all();
+        let holdings: Vec<Vec<[Card; 2]>> = hands.iter().map(StartingHand::holdings).collect();
+        let n = hands.len();
+
+        let mut equities = vec![vec![0.5; n]; n];
+        let mut deals = vec![vec![0; n]; n];
+        for i in 0..n {
+            for j in 0..n {
+                deals[i][j] = holdings[i]
+                    .iter()
+                    .flat_map(|hero| holdings[j].iter().map(move |villain| (hero, villain)))
+                    .filter(|(hero, villain)| hero.iter().all(|card| !villain.contains(card)))
+                    .count() as u32;
+            }
+        }
+
+        for i in 0..n {
+            for j in i + 1..n {
+                let cell = (i * n + j) as u64;
+                let mut rng = Rng::new(seed ^ cell.wrapping_mul(0x9e3779b97f4a7c15));
+                let equity = heads_up_equity(&holdings[i], &holdings[j], trials, &mut rng);
+                equities[i][j] = equity;
+                equities[j][i] = 1.0 - equity;
+            }
+        }
+
+        Some(HeadsUpEquities {
+            hands,
+            trials,
+            seed,
+            equities,
+            deals,
+        })
+    }
+
+    pub fn equity(&self, hero: StartingHand, villain: StartingHand) -> Option<f64> {
+        Some(self.equities[self.index(hero)?][self.index(villain)?])
+    }
+
+    /// How many ways the two hands can be dealt together.
+    pub fn deals(&self, hero: StartingHand, villain: StartingHand) -> Option<u32> {
+        Some(self.deals[self.index(hero)?][self.index(villain)?])
+    }
+
+    fn index(&self, hand: StartingHand) -> Option<usize> {
+        self.hands.iter().position(|&h| h == hand)
+    }
+
+    // The same as above, by index into `hands`.
+    pub(crate) fn by_index(&self, hero: usize, villain: usize) -> (f64, u32) {
+        (self.equities[hero][villain], self.deals[hero][villain])
+    }
+}
+
+// Monte Carlo equity of one range of holdings against another, dealing a
+// random holding from each that doesn't clash, then a random board.
+fn heads_up_equity(hero: &[[Card; 2]], villain: &[[Card; 2]], trials: usize, rng: &mut Rng) -> f64 {
+    let mut share = 0.0;
+    let mut cards = [Card::new(Rank::Two, Suit::Spades); 7];
+    for _ in 0..trials {
+        let (ours, theirs) = loop {
+            let (ours, theirs) = (
+                hero[rng.below(hero.len())],
+                villain[rng.below(villain.len())],
+            );
+            if ours.iter().all(|card| !theirs.contains(card)) {
+                break (ours, theirs);
+            }
+        };
+
+        let mut deck: Vec<Card> = Card::all()
+            .filter(|card| !ours.contains(card) && !theirs.contains(card))
+            .collect();
+        for i in 0..5 {
+            let j = i + rng.below(deck.len() - i);
+            deck.swap(i, j);
+        }
+
+        cards[2..].copy_from_slice(&deck[..5]);
+        cards[..2].copy_from_slice(&ours);
+        let our_strength = best_strength(&cards);
+        cards[..2].copy_from_slice(&theirs);
+        let their_strength = best_strength(&cards);
+
+        share += match our_strength.cmp(&their_strength) {
+            std::cmp::Ordering::Greater => 1.0,
+            std::cmp::Ordering::Equal => 0.5,
+            std::cmp::Ordering::Less => 0.0,
+        };
+    }
+
+    share / trials as f64
+}
@@ -0,0 +1,196 @@
+use std::fmt;
+
+use crate::{evaluate, showdown, Card, HandRank, Rank, ShowdownError, ShowdownHand};
+
+/// How a card room takes rake from each pot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RakeRule {
+    /// In hundredths of a percent, so 500 is 5%.
+    pub basis_points: u32,
+    /// The most taken from one pot.
+    pub cap: u64,
+    /// Take nothing from a hand that ends before the flop.
+    pub no_flop_no_drop: bool,
+}
+
+impl Default for RakeRule {
+    /// 5% up to 3 big blinds of 100, with no flop, no drop.
+    fn default() -> RakeRule {
+        RakeRule {
+            basis_points: 500,
+            cap: 300,
+            no_flop_no_drop: true,
+        }
+    }
+}
+
+/// The rake taken from one pot, with how it was worked out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rake {
+    pub pot: u64,
+    /// The percentage of the pot, rounded down, before the cap.
+    pub uncapped: u64,
+    pub taken: u64,
+    pub reason: RakeReason,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RakeReason {
+    NoFlop,
+    Percentage,
+    Capped,
+}
+
+impl RakeRule {
+    pub fn rake(&self, pot: u64, saw_flop: bool) -> Rake {
+        let uncapped = pot * u64::from(self.basis_points) / 10_000;
+        let (taken, reason) = match (self.no_flop_no_drop && !saw_flop, uncapped > self.cap) {
+            (true, _) => (0, RakeReason::NoFlop),
+            (false, true) => (self.cap, RakeReason::Capped),
+            (false, false) => (uncapped, RakeReason::Percentage),
+        };
+
+        Rake {
+            pot,
+            uncapped,
+            taken,
+            reason,
+        }
+    }
+}
+
+impl fmt::Display for Rake {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.reason {
+            RakeReason::NoFlop => write!(f, "no flop, no drop: nothing taken from {}", self.pot),
+            RakeReason::Percentage => write!(f, "{} taken from {}", self.taken, self.pot),
+            RakeReason::Capped => write!(
+                f,
+                "{} taken from {}, capped from {}",
+                self.taken, self.pot, self.uncapped
+            ),
+        }
+    }
+}
+
+/// When a hold'em hand pays the bad beat jackpot: a strong enough hand has
+/// to lose at showdown, usually with both its hole cards playing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BadBeatRule {
+    /// The weakest losing hand that qualifies, by category and then the
+    /// rank of its first card, e.g. four of a kind and jack for quad jacks.
+    pub category: HandRank,
+    pub rank: Rank,
+    /// How many of the loser's hole cards have to play.
+    pub loser_hole_cards: usize,
+    /// How many of the winner's hole cards have to play.
+    pub winner_hole_cards: usize,
+}
+
+impl Default for BadBeatRule {
+    /// Quad jacks or better beaten, with both of the loser's hole cards and
+    /// at least one of the winner's playing.
+    fn default() -> BadBeatRule {
+        BadBeatRule {
+            category: HandRank::FourOfAKind,
+            rank: Rank::Jack,
+            loser_hole_cards: 2,
+            winner_hole_cards: 1,
+        }
+    }
+}
+
+/// A hand that qualified for the bad beat jackpot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BadBeat {
+    /// Indices into the hole cards given.
+    pub loser: usize,
+    pub winner: usize,
+    pub losing_hand: ShowdownHand,
+    pub winning_hand: ShowdownHand,
+    /// How many hole cards each hand couldn't be made without.
+    pub loser_hole_cards: usize,
+    pub winner_hole_cards: usize,
+}
+
+impl fmt::Display for BadBeat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "hand {} lost with {} ({} hole cards playing) to hand {} with {} ({} hole cards playing)",
+            self.loser,
+            self.losing_hand.description,
+            self.loser_hole_cards,
+            self.winner,
+            self.winning_hand.description,
+            self.winner_hole_cards
+        )
+    }
+}
+
+impl BadBeatRule {
+    /// The bad beat at a hold'em showdown, if there is one. The loser is the
+    /// best hand that didn't win.
+    pub fn check(
+        &self,
+        board: &[Card],
+        holes: &[[Card; 2]],
+    ) -> Result<Option<BadBeat>, ShowdownError> {
+        let hands: Vec<Vec<Card>> = holes
+            .iter()
+            .map(|hole| hole.iter().chain(board).copied().collect())
+            .collect();
+        let result = showdown(&hands)?;
+
+        let loser = (0..holes.len())
+            .filter(|i| !result.winners.contains(i))
+            .max_by_key(|&i| result.hands[i].strength);
+        let (Some(loser), Some(&winner)) = (loser, result.winners.first()) else {
+            return Ok(None);
+        };
+
+        let losing_hand = &result.hands[loser];
+        let first_rank = losing_hand.cards[0].rank;
+        if (losing_hand.category, first_rank) < (self.category, self.rank) {
+            return Ok(None);
+        }
+        let loser_hole_cards = hole_cards_played(&holes[loser], board);
+        let winner_hole_cards = hole_cards_played(&holes[winner], board);
+        if loser_hole_cards < self.loser_hole_cards || winner_hole_cards < self.winner_hole_cards {
+            return Ok(None);
+        }
+
+        Ok(Some(BadBeat {
+            loser,
+            winner,
+            losing_hand: losing_hand.clone(),
+            winning_hand: result.hands[winner].clone(),
+            loser_hole_cards,
+            winner_hole_cards,
+        }))
+    }
+}
+
+/// How many hole cards the best hand can't be made without. A hole card
+/// that a board card of the same rank could stand in for doesn't play,
+/// even if it happens to be one of the best hand's five cards.
+pub fn hole_cards_played(hole: &[Card], board: &[Card]) -> usize {
+    let strength = |cards: Vec<Card>| evaluate(&cards).map_or(0, |evaluation| evaluation.strength);
+    let best = strength(hole.iter().chain(board).copied().collect());
+
+    let mut subsets: Vec<u32> = (0..1 << hole.len()).collect();
+    subsets.sort_by_key(|subset| subset.count_ones());
+    subsets
+        .into_iter()
+        .find(|subset| {
+            let cards = hole
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| subset >> i & 1 == 1)
+                .map(|(_, &card)| card)
+                .chain(board.iter().copied())
+                .collect();
+            strength(cards) == best
+        })
+        .map_or(hole.len(), |subset| subset.count_ones() as usize)
+}
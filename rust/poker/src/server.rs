@@ -0,0 +1,529 @@
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::{parse_cards, Action, Card, Deck, Game, Rng};
+
+// Give up on a client's turn after this many unusable replies, and check or
+// fold for them instead.
+const MAX_BAD_REPLIES: usize = 3;
+
+#[derive(Debug, Clone)]
+pub struct ServerConfig {
+    pub seats: usize,
+    pub stack: u32,
+    pub small_blind: u32,
+    pub big_blind: u32,
+    /// Stop after this many hands, or sooner if only one player has chips.
+    pub hands: usize,
+    pub seed: u64,
+    /// How long to wait for a client to act before checking or folding.
+    pub timeout: Duration,
+}
+
+impl Default for ServerConfig {
+    fn default() -> ServerConfig {
+        ServerConfig {
+            seats: 2,
+            stack: 1000,
+            small_blind: 5,
+            big_blind: 10,
+            hands: 100,
+            seed: 0,
+            timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+/// What a client is told when it's their turn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Turn {
+    pub to_call: u32,
+    pub min_raise_to: u32,
+    pub max_raise_to: u32,
+    pub pot: u32,
+}
+
+/// A line of the table protocol. Each message is a single line of
+/// space-separated words, starting with an upper case keyword.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Message {
+    // Client to server.
+    Join(String),
+    Play(Action),
+    // Server to client.
+    Seat(usize),
+    Hand {
+        number: usize,
+        button: usize,
+        stacks: Vec<u32>,
+    },
+    Hole([Card; 2]),
+    Board(Vec<Card>),
+    Turn(Turn),
+    Acted {
+        seat: usize,
+        action: Action,
+    },
+    Show {
+        seat: usize,
+        hole: [Card; 2],
+        description: String,
+    },
+    Win {
+        seat: usize,
+        amount: u32,
+    },
+    End,
+    GameOver(Vec<u32>),
+    Error(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseMessageError(String);
+
+impl fmt::Display for ParseMessageError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unrecognised message {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseMessageError {}
+
+fn write_action(f: &mut fmt::Formatter, action: &Action) -> fmt::Result {
+    match action {
+        Action::Fold => write!(f, "FOLD"),
+        Action::Check => write!(f, "CHECK"),
+        Action::Call => write!(f, "CALL"),
+        Action::Raise(to) => write!(f, "RAISE {to}"),
+    }
+}
+
+fn write_list<T: fmt::Display>(f: &mut fmt::Formatter, items: &[T]) -> fmt::Result {
+    for item in items {
+        write!(f, " {item}")?;
+    }
+    Ok(())
+}
+
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Message::Join(name) => write!(f, "JOIN {name}"),
+            Message::Play(action) => write_action(f, action),
+            Message::Seat(seat) => write!(f, "SEAT {seat}"),
+            Message::Hand {
+                number,
+                button,
+                stacks,
+            } => {
+                write!(f, "HAND {number} {button}")?;
+                write_list(f, stacks)
+            }
+            Message::Hole([a, b]) => write!(f, "HOLE {a} {b}"),
+            Message::Board(cards) => {
+                write!(f, "BOARD")?;
+                write_list(f, cards)
+            }
+            Message::Turn(turn) => write!(
+                f,
+                "TURN {} {} {} {}",
+                turn.to_call, turn.min_raise_to, turn.max_raise_to, turn.pot
+            ),
+            Message::Acted { seat, action } => {
+                write!(f, "ACTED {seat} ")?;
+                write_action(f, action)
+            }
+            Message::Show {
+                seat,
+                hole: [a, b],
+                description,
+            } => write!(f, "SHOW {seat} {a} {b} {description}"),
+            Message::Win { seat, amount } => write!(f, "WIN {seat} {amount}"),
+            Message::End => write!(f, "END"),
+            Message::GameOver(stacks) => {
+                write!(f, "GAMEOVER")?;
+                write_list(f, stacks)
+            }
+            Message::Error(reason) => write!(f, "ERROR {reason}"),
+        }
+    }
+}
+
+fn parse_action(words: &[&str]) -> Option<Action> {
+    match words {
+        ["FOLD"] => Some(Action::Fold),
+        ["CHECK"] => Some(Action::Check),
+        ["CALL"] => Some(Action::Call),
+        ["RAISE", to] => to.parse().ok().map(Action::Raise),
+        _ => None,
+    }
+}
+
+fn parse_hole(cards: &[&str]) -> Option<[Card; 2]> {
+    match cards {
+        [a, b] => Some([a.parse().ok()?, b.parse().ok()?]),
+        _ => None,
+    }
+}
+
+fn parse_numbers<T: FromStr>(words: &[&str]) -> Option<Vec<T>> {
+    words.iter().map(|word| word.parse().ok()).collect()
+}
+
+impl FromStr for Message {
+    type Err = ParseMessageError;
+
+    fn from_str(line: &str) -> Result<Message, ParseMessageError> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        parse_message(&words).ok_or_else(|| ParseMessageError(line.trim_end().to_string()))
+    }
+}
+
+fn parse_message(words: &[&str]) -> Option<Message> {
+    let rest = |n: usize| words[n..].join(" ");
+
+    let message = match words {
+        ["JOIN", _, ..] => Message::Join(rest(1)),
+        ["SEAT", seat] => Message::Seat(seat.parse().ok()?),
+        ["HAND", number, button, stacks @ ..] => Message::Hand {
+            number: number.parse().ok()?,
+            button: button.parse().ok()?,
+            stacks: parse_numbers(stacks)?,
+        },
+        ["HOLE", cards @ ..] => Message::Hole(parse_hole(cards)?),
+        ["BOARD", ..] => Message::Board(parse_cards(&rest(1)).ok()?),
+        ["TURN", to_call, min_raise_to, max_raise_to, pot] => Message::Turn(Turn {
+            to_call: to_call.parse().ok()?,
+            min_raise_to: min_raise_to.parse().ok()?,
+            max_raise_to: max_raise_to.parse().ok()?,
+            pot: pot.parse().ok()?,
+        }),
+        ["ACTED", seat, action @ ..] => Message::Acted {
+            seat: seat.parse().ok()?,
+            action: parse_action(action)?,
+        },
+        ["SHOW", seat, a, b, _, ..] => Message::Show {
+            seat: seat.parse().ok()?,
+            hole: parse_hole(&[a, b])?,
+            description: rest(4),
+        },
+        ["WIN", seat, amount] => Message::Win {
+            seat: seat.parse().ok()?,
+            amount: amount.parse().ok()?,
+        },
+        ["END"] => Message::End,
+        ["GAMEOVER", stacks @ ..] => Message::GameOver(parse_numbers(stacks)?),
+        ["ERROR", ..] => Message::Error(rest(1)),
+        action => Message::Play(parse_action(action)?),
+    };
+
+    Some(message)
+}
+
+struct Connection {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+    connected: bool,
+}
+
+impl Connection {
+    fn new(stream: TcpStream, timeout: Duration) -> io::Result<Connection> {
+        stream.set_read_timeout(Some(timeout))?;
+        // Messages are small and each waits on a reply, so don't let Nagle's
+        // algorithm hold them back.
+        stream.set_nodelay(true)?;
+        Ok(Connection {
+            reader: BufReader::new(stream.try_clone()?),
+            writer: stream,
+            connected: true,
+        })
+    }
+
+    // A client that has gone away, or stopped answering, is treated as
+    // disconnected for the rest of the game rather than failing the table.
+    fn send(&mut self, message: &Message) {
+        if self.connected && writeln!(self.writer, "{message}").is_err() {
+            self.connected = false;
+        }
+    }
+
+    fn recv(&mut self) -> Option<String> {
+        let mut line = String::new();
+        match self.connected && matches!(self.reader.read_line(&mut line), Ok(n) if n > 0) {
+            true => Some(line),
+            false => {
+                self.connected = false;
+                None
+            }
+        }
+    }
+}
+
+/// Hosts a table for clients connecting over TCP, using the line-based
+/// protocol described by [`Message`].
+pub struct Server {
+    listener: TcpListener,
+    config: ServerConfig,
+}
+
+impl Server {
+    pub fn bind(addr: impl ToSocketAddrs, config: ServerConfig) -> io::Result<Server> {
+        Ok(Server {
+            listener: TcpListener::bind(addr)?,
+            config,
+        })
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Wait for every seat to be taken, play the game out, and return the
+    /// final stacks.
+    pub fn run(self) -> io::Result<Vec<u32>> {
+        let config = &self.config;
+        let mut conns = Vec::with_capacity(config.seats);
+        while conns.len() < config.seats {
+            let (stream, _) = self.listener.accept()?;
+            let mut conn = Connection::new(stream, config.timeout)?;
+            match conn.recv().map(|line| line.parse()) {
+                Some(Ok(Message::Join(_))) => {
+                    conn.send(&Message::Seat(conns.len()));
+                    conns.push(conn);
+                }
+                _ => conn.send(&Message::Error("expected JOIN <name>".to_string())),
+            }
+        }
+
+        let mut stacks = vec![config.stack; config.seats];
+        let mut rng = Rng::new(config.seed);
+        let mut button = 0;
+        for number in 1..=config.hands {
+            let active: Vec<usize> = (0..config.seats).filter(|&seat| stacks[seat] > 0).collect();
+            if active.len() < 2 {
+                break;
+            }
+
+            let game_stacks: Vec<u32> = active.iter().map(|&seat| stacks[seat]).collect();
+            let game_button = active.iter().position(|&seat| seat >= button).unwrap_or(0);
+            let mut game = Game::new(
+                &game_stacks,
+                game_button,
+                config.small_blind,
+                config.big_blind,
+                Deck::shuffled(&mut rng),
+            )
+            .map_err(io::Error::other)?;
+
+            broadcast(
+                &mut conns,
+                &Message::Hand {
+                    number,
+                    button: active[game.button()],
+                    stacks: stacks.clone(),
+                },
+            );
+            for (i, &seat) in active.iter().enumerate() {
+                conns[seat].send(&Message::Hole(game.seats()[i].hole));
+            }
+
+            let mut board = 0;
+            while let Some(i) = game.to_act() {
+                if game.board().len() != board {
+                    board = game.board().len();
+                    broadcast(&mut conns, &Message::Board(game.board().to_vec()));
+                }
+                let seat = active[i];
+                let action = take_turn(&mut conns[seat], &mut game);
+                broadcast(&mut conns, &Message::Acted { seat, action });
+            }
+            if game.board().len() != board {
+                broadcast(&mut conns, &Message::Board(game.board().to_vec()));
+            }
+
+            for (i, evaluation) in game.showdown() {
+                let message = Message::Show {
+                    seat: active[i],
+                    hole: game.seats()[i].hole,
+                    description: evaluation.description(),
+                };
+                broadcast(&mut conns, &message);
+            }
+            for (i, &amount) in game.payouts().unwrap_or_default().iter().enumerate() {
+                if amount > 0 {
+                    broadcast(
+                        &mut conns,
+                        &Message::Win {
+                            seat: active[i],
+                            amount,
+                        },
+                    );
+                }
+            }
+            broadcast(&mut conns, &Message::End);
+
+            for (i, &seat) in active.iter().enumerate() {
+                stacks[seat] = game.seats()[i].stack;
+            }
+            button = active[(game.button() + 1) % active.len()];
+        }
+
+        broadcast(&mut conns, &Message::GameOver(stacks.clone()));
+        Ok(stacks)
+    }
+}
+
+fn broadcast(conns: &mut [Connection], message: &Message) {
+    for conn in conns {
+        conn.send(message);
+    }
+}
+
+// Ask a client for an action until they give a legal one, apply it and
+// return it.
+fn take_turn(conn: &mut Connection, game: &mut Game) -> Action {
+    let i = game.to_act().expect("a seat should be waiting to act");
+    let turn = Turn {
+        to_call: game.to_call(i),
+        min_raise_to: game.min_raise_to().min(game.max_raise_to(i)),
+        max_raise_to: game.max_raise_to(i),
+        pot: game.pot(),
+    };
+
+    for _ in 0..MAX_BAD_REPLIES {
+        conn.send(&Message::Turn(turn));
+        let reply = match conn.recv() {
+            Some(line) => line.parse(),
+            None => break,
+        };
+        let result = match reply {
+            Ok(Message::Play(action)) => game
+                .apply(action)
+                .map(|_| action)
+                .map_err(|err| err.to_string()),
+            Ok(_) => Err("expected FOLD, CHECK, CALL or RAISE <to>".to_string()),
+            Err(err) => Err(err.to_string()),
+        };
+        match result {
+            Ok(action) => return action,
+            Err(reason) => conn.send(&Message::Error(reason)),
+        }
+    }
+
+    let action = match turn.to_call {
+        0 => Action::Check,
+        _ => Action::Fold,
+    };
+    game.apply(action)
+        .expect("checking or folding should always be legal");
+    action
+}
+
+/// A client for the table protocol, which plays by asking a callback what to
+/// do on each turn. Mostly useful for scripted bots and tests.
+pub struct Client {
+    seat: usize,
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+    hole: Option<[Card; 2]>,
+    board: Vec<Card>,
+    stacks: Vec<u32>,
+}
+
+impl Client {
+    /// Connect to a server and take a seat.
+    pub fn connect(addr: impl ToSocketAddrs, name: &str) -> io::Result<Client> {
+        let stream = TcpStream::connect(addr)?;
+        stream.set_nodelay(true)?;
+        let mut client = Client {
+            seat: 0,
+            reader: BufReader::new(stream.try_clone()?),
+            writer: stream,
+            hole: None,
+            board: vec![],
+            stacks: vec![],
+        };
+
+        client.send(&Message::Join(name.to_string()))?;
+        match client.recv()? {
+            Some(Message::Seat(seat)) => client.seat = seat,
+            other => {
+                let reason = format!("expected a seat, got {other:?}");
+                return Err(io::Error::new(io::ErrorKind::InvalidData, reason));
+            }
+        }
+
+        Ok(client)
+    }
+
+    pub fn seat(&self) -> usize {
+        self.seat
+    }
+
+    pub fn hole(&self) -> Option<[Card; 2]> {
+        self.hole
+    }
+
+    pub fn board(&self) -> &[Card] {
+        &self.board
+    }
+
+    /// Stacks at the start of the current hand.
+    pub fn stacks(&self) -> &[u32] {
+        &self.stacks
+    }
+
+    pub fn send(&mut self, message: &Message) -> io::Result<()> {
+        writeln!(self.writer, "{message}")
+    }
+
+    /// The next message from the server, or `None` if it has hung up.
+    pub fn recv(&mut self) -> io::Result<Option<Message>> {
+        let mut line = String::new();
+        if self.reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+
+        let message = line
+            .parse()
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        match &message {
+            Message::Hand { stacks, .. } => {
+                self.hole = None;
+                self.board.clear();
+                self.stacks = stacks.clone();
+            }
+            Message::Hole(hole) => self.hole = Some(*hole),
+            Message::Board(board) => self.board = board.clone(),
+            _ => {}
+        }
+
+        Ok(Some(message))
+    }
+
+    /// Play until the game is over, asking `decide` what to do on each turn,
+    /// and return the final stacks.
+    pub fn play(
+        mut self,
+        mut decide: impl FnMut(&Client, &Turn) -> Action,
+    ) -> io::Result<Vec<u32>> {
+        while let Some(message) = self.recv()? {
+            match message {
+                Message::GameOver(stacks) => return Ok(stacks),
+                Message::Turn(turn) => {
+                    let action = decide(&self, &turn);
+                    self.send(&Message::Play(action))?;
+                }
+                _ => {}
+            }
+        }
+
+        Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "server hung up before the game was over",
+        ))
+    }
+}
@@ -0,0 +1,107 @@
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{evaluate, Card, Evaluation, HandRank, SuitOrder};
+
+/// The result of comparing hands at showdown.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Showdown {
+    /// Indices of the best hands, in the order the hands were given. More
+    /// than one means the pot is split.
+    pub winners: Vec<usize>,
+    /// Every hand, in the order given.
+    pub hands: Vec<ShowdownHand>,
+}
+
+/// One hand's best five cards and how they rank.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ShowdownHand {
+    pub category: HandRank,
+    /// As in [`Evaluation::strength`].
+    pub strength: u32,
+    /// Ordered by significance, as in [`Evaluation::cards`].
+    pub cards: [Card; 5],
+    /// As in [`Evaluation::kickers`].
+    pub kickers: Vec<Card>,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShowdownError {
+    /// A hand, given by its index, had fewer than five cards.
+    NotEnoughCards { hand: usize, cards: usize },
+    /// A hand, given by its index, had the same card more than once.
+    DuplicateCard { hand: usize, card: Card },
+}
+
+impl fmt::Display for ShowdownError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ShowdownError::NotEnoughCards { hand, cards } => {
+                write!(f, "hand {hand} has {cards} cards, at least 5 are needed")
+            }
+            ShowdownError::DuplicateCard { hand, card } => {
+                write!(f, "hand {hand} has {card} more than once")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ShowdownError {}
+
+impl Showdown {
+    /// The one winner when ties are broken by suit, or `None` if there were
+    /// no hands. Of two identical hands the first given wins.
+    pub fn winner_by_suit(&self, order: &SuitOrder) -> Option<usize> {
+        let key = |i: usize| order.key(self.hands[i].strength, &self.hands[i].cards);
+        self.winners
+            .iter()
+            .copied()
+            .reduce(|best, i| if key(i) > key(best) { i } else { best })
+    }
+}
+
+impl From<Evaluation> for ShowdownHand {
+    fn from(evaluation: Evaluation) -> ShowdownHand {
+        ShowdownHand {
+            category: evaluation.category,
+            strength: evaluation.strength,
+            kickers: evaluation.kickers().to_vec(),
+            description: evaluation.description(),
+            cards: evaluation.cards,
+        }
+    }
+}
+
+/// Evaluate each hand from five or more cards and find the winners.
+pub fn showdown<H: AsRef<[Card]>>(hands: &[H]) -> Result<Showdown, ShowdownError> {
+    let hands = hands
+        .iter()
+        .enumerate()
+        .map(|(i, hand)| {
+            let cards = hand.as_ref();
+            for (j, &card) in cards.iter().enumerate() {
+                if cards[..j].contains(&card) {
+                    return Err(ShowdownError::DuplicateCard { hand: i, card });
+                }
+            }
+            evaluate(cards)
+                .map(ShowdownHand::from)
+                .ok_or(ShowdownError::NotEnoughCards {
+                    hand: i,
+                    cards: cards.len(),
+                })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let best = hands.iter().map(|hand| hand.strength).max();
+    let winners = (0..hands.len())
+        .filter(|&i| Some(hands[i].strength) == best)
+        .collect();
+
+    Ok(Showdown { winners, hands })
+}
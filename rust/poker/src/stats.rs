@@ -0,0 +1,187 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::{evaluate, Accumulator, Card, HandRank};
+
+/// Every hand that evaluates the same, e.g. all the ways of holding a
+/// king high straight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HandClass {
+    pub category: HandRank,
+    /// As in [`crate::Evaluation::strength`].
+    pub strength: u32,
+    /// How many deals make this hand.
+    pub count: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CategoryStats {
+    pub category: HandRank,
+    pub count: u64,
+    /// How many different hands of the category there are.
+    pub distinct: usize,
+    pub probability: f64,
+}
+
+/// How the best hand falls over every deal of five or seven cards.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HandDistribution {
+    pub cards: usize,
+    pub total: u64,
+    /// Strongest first.
+    pub classes: Vec<HandClass>,
+}
+
+impl HandDistribution {
+    /// Evaluates all 2,598,960 five card hands.
+    pub fn five_card() -> HandDistribution {
+        let deck: Vec<Card> = Card::all().collect();
+        HandDistribution::enumerate(5, |dealt, _| {
+            let hand = [0, 1, 2, 3, 4].map(|i| deck[dealt[i]]);
+            let evaluation = evaluate(&hand).expect("five cards");
+            (evaluation.category, evaluation.strength)
+        })
+    }
+
+    /// The best five of all 133,784,560 seven card hands. This works the
+    /// best hand out from rank counts rather than trying all 21 fives, but it
+    /// still takes a while outside a release build.
+    pub fn seven_card() -> HandDistribution {
+        HandDistribution::enumerate(7, |_, hand| hand.strength().expect("seven cards"))
+    }
+
+    // Deal every hand of `size` cards, adding and removing each card from an
+    // accumulator as it goes in and out.
+    fn enumerate<F>(size: usize, mut score: F) -> HandDistribution
+    where
+        F: FnMut([usize; 7], &Accumulator) -> (HandRank, u32),
+    {
+        let mut classes: HashMap<u32, HandClass> = HashMap::new();
+        let deck: Vec<Card> = Card::all().collect();
+        let mut dealt = [0; 7];
+        let mut hand = Accumulator::new();
+
+        let mut deal = |dealt: &[usize; 7], hand: &Accumulator| {
+            let (category, strength) = score(*dealt, hand);
+            classes
+                .entry(strength)
+                .or_insert(HandClass {
+                    category,
+                    strength,
+                    count: 0,
+                })
+                .count += 1;
+        };
+        deal_from(&deck, 0, 0, size, &mut dealt, &mut hand, &mut deal);
+
+        let mut classes: Vec<HandClass> = classes.into_values().collect();
+        classes.sort_unstable_by_key(|class| Reverse(class.strength));
+
+        HandDistribution {
+            cards: size,
+            total: classes.iter().map(|class| class.count).sum(),
+            classes,
+        }
+    }
+
+    /// Totals for each category, strongest first.
+    pub fn categories(&self) -> Vec<CategoryStats> {
+        let mut categories: Vec<CategoryStats> = vec![];
+        for class in &self.classes {
+            match categories.last_mut() {
+                Some(stats) if stats.category == class.category => {
+                    stats.count += class.count;
+                    stats.distinct += 1;
+                }
+                _ => categories.push(CategoryStats {
+                    category: class.category,
+                    count: class.count,
+                    distinct: 1,
+                    probability: 0.0,
+                }),
+            }
+        }
+        for stats in &mut categories {
+            stats.probability = stats.count as f64 / self.total as f64;
+        }
+        categories
+    }
+
+    pub fn category(&self, category: HandRank) -> Option<CategoryStats> {
+        self.categories()
+            .into_iter()
+            .find(|stats| stats.category == category)
+    }
+
+    /// One row per category: its name, count, distinct hands and
+    /// probability.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("category,count,distinct,probability\n");
+        for stats in self.categories() {
+            csv += &format!(
+                "{},{},{},{:.8}\n",
+                stats.category, stats.count, stats.distinct, stats.probability
+            );
+        }
+        csv
+    }
+}
+
+impl fmt::Display for HandDistribution {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<16}{:>12}{:>10}{:>12}",
+            "hand", "count", "distinct", "probability"
+        )?;
+        for stats in self.categories() {
+            writeln!(
+                f,
+                "{:<16}{:>12}{:>10}{:>11.4}%",
+                stats.category.to_string(),
+                stats.count,
+                stats.distinct,
+                stats.probability * 100.0
+            )?;
+        }
+        writeln!(
+            f,
+            "{:<16}{:>12}{:>10}",
+            "total",
+            self.total,
+            self.classes.len()
+        )
+    }
+}
+
+fn deal_from<F>(
+    deck: &[Card],
+    next: usize,
+    depth: usize,
+    size: usize,
+    dealt: &mut [usize; 7],
+    hand: &mut Accumulator,
+    deal: &mut F,
+) where
+    F: FnMut(&[usize; 7], &Accumulator),
+{
+    if depth == size {
+        deal(dealt, hand);
+        return;
+    }
+    // Leave enough cards to fill the rest of the hand.
+    for card in next..=deck.len() - (size - depth) {
+        dealt[depth] = card;
+        hand.add(deck[card]);
+        deal_from(deck, card + 1, depth + 1, size, dealt, hand, deal);
+        hand.remove(deck[card]);
+    }
+}
+
+// The strength of the best five of up to seven different cards, as
+// `evaluate` would give it but without trying every five.
+pub(crate) fn best_strength(cards: &[Card]) -> u32 {
+    let hand: Accumulator = cards.iter().copied().collect();
+    hand.strength().expect("five or more cards").1
+}
@@ -0,0 +1,76 @@
+use core::cmp::{Ordering, Reverse};
+
+use crate::{Card, Evaluation, Suit};
+
+/// A ranking of the suits, for games that break ties by suit rather than
+/// splitting the pot, e.g. a stud bring-in or drawing cards for seats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SuitOrder {
+    // Highest first.
+    order: [Suit; 4],
+}
+
+impl SuitOrder {
+    /// The suits from highest to lowest, or `None` unless each comes once.
+    pub fn new(order: [Suit; 4]) -> Option<SuitOrder> {
+        Suit::ALL
+            .iter()
+            .all(|suit| order.contains(suit))
+            .then_some(SuitOrder { order })
+    }
+
+    /// Spades, hearts, diamonds, clubs: the bridge order used for stud
+    /// bring-ins and drawing for seats.
+    pub const fn bridge() -> SuitOrder {
+        SuitOrder {
+            order: [Suit::Spades, Suit::Hearts, Suit::Diamonds, Suit::Clubs],
+        }
+    }
+
+    /// Spades, hearts, clubs, diamonds, as in Big Two.
+    pub const fn big_two() -> SuitOrder {
+        SuitOrder {
+            order: [Suit::Spades, Suit::Hearts, Suit::Clubs, Suit::Diamonds],
+        }
+    }
+
+    /// Highest first.
+    pub fn order(&self) -> [Suit; 4] {
+        self.order
+    }
+
+    /// 3 for the highest suit down to 0 for the lowest.
+    pub fn value(&self, suit: Suit) -> u8 {
+        let position = self.order.iter().position(|&s| s == suit);
+        3 - position.expect("every suit is ordered") as u8
+    }
+
+    /// By rank, then by suit.
+    pub fn compare_cards(&self, a: Card, b: Card) -> Ordering {
+        (a.rank, self.value(a.suit)).cmp(&(b.rank, self.value(b.suit)))
+    }
+
+    /// By strength, then by the suits of the cards in order of significance,
+    /// the best suit first among cards of the same rank. So a pair of kings
+    /// with the king of spades beats one without, and two different hands
+    /// from one deck are never equal.
+    pub fn compare(&self, a: &Evaluation, b: &Evaluation) -> Ordering {
+        self.key(a.strength, &a.cards)
+            .cmp(&self.key(b.strength, &b.cards))
+    }
+
+    // What `compare` orders hands by.
+    pub(crate) fn key(&self, strength: u32, cards: &[Card; 5]) -> (u32, [u8; 5]) {
+        let mut suits = cards.map(|card| (card.rank, self.value(card.suit)));
+        for group in suits.chunk_by_mut(|a, b| a.0 == b.0) {
+            group.sort_unstable_by_key(|&(_, value)| Reverse(value));
+        }
+        (strength, suits.map(|(_, value)| value))
+    }
+}
+
+impl Default for SuitOrder {
+    fn default() -> SuitOrder {
+        SuitOrder::bridge()
+    }
+}
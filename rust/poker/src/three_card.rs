@@ -0,0 +1,269 @@
+use std::cmp::{Ordering, Reverse};
+use std::fmt;
+
+use crate::combinations::next_combination;
+use crate::{Card, Rank};
+
+// The dealer hands left once the player has three cards: 49 choose 3.
+const DEALER_HANDS: i64 = 18424;
+
+/// Three Card Poker categories, lowest first. With only three cards a
+/// straight is harder to make than a flush, so it ranks above one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ThreeCardRank {
+    HighCard,
+    Pair,
+    Flush,
+    Straight,
+    ThreeOfAKind,
+    StraightFlush,
+}
+
+impl fmt::Display for ThreeCardRank {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            ThreeCardRank::HighCard => "high card",
+            ThreeCardRank::Pair => "pair",
+            ThreeCardRank::Flush => "flush",
+            ThreeCardRank::Straight => "straight",
+            ThreeCardRank::ThreeOfAKind => "three of a kind",
+            ThreeCardRank::StraightFlush => "straight flush",
+        };
+        f.write_str(s)
+    }
+}
+
+/// A three card hand, ranked by Three Card Poker rules.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThreeCardHand {
+    pub category: ThreeCardRank,
+    /// The category and cards packed into one comparable number, as in
+    /// [`crate::Evaluation`], but only meaningful against other three card
+    /// hands.
+    pub strength: u32,
+    /// Ordered by significance, with the ace last in A,2,3.
+    pub cards: [Card; 3],
+}
+
+impl PartialOrd for ThreeCardHand {
+    fn partial_cmp(&self, other: &ThreeCardHand) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ThreeCardHand {
+    fn cmp(&self, other: &ThreeCardHand) -> Ordering {
+        self.strength.cmp(&other.strength)
+    }
+}
+
+pub fn evaluate_three_card(mut cards: [Card; 3]) -> ThreeCardHand {
+    cards.sort_by_key(|card| (Reverse(card.rank), card.suit));
+    let [high, mid, low] = cards.map(|card| card.rank);
+
+    let is_flush = cards.iter().all(|card| card.suit == cards[0].suit);
+    let is_wheel = [high, mid, low] == [Rank::Ace, Rank::Three, Rank::Two];
+    let is_straight = is_wheel || (high as u32 == mid as u32 + 1 && mid as u32 == low as u32 + 1);
+
+    let category = match (is_straight, is_flush) {
+        (true, true) => ThreeCardRank::StraightFlush,
+        _ if high == low => ThreeCardRank::ThreeOfAKind,
+        (true, false) => ThreeCardRank::Straight,
+        (false, true) => ThreeCardRank::Flush,
+        _ if high == mid || mid == low => ThreeCardRank::Pair,
+        _ => ThreeCardRank::HighCard,
+    };
+    // Put a pair ahead of its kicker.
+    if mid == low && high != mid {
+        cards.rotate_left(1);
+    }
+    if is_wheel {
+        cards.rotate_left(1);
+    }
+
+    let strength = cards.iter().fold(category as u32, |strength, card| {
+        let value = match (is_wheel, card.rank) {
+            (true, Rank::Ace) => 0,
+            (_, rank) => rank as u32 + 1,
+        };
+        strength << 4 | value
+    });
+
+    ThreeCardHand {
+        category,
+        strength,
+        cards,
+    }
+}
+
+impl ThreeCardHand {
+    /// The dealer needs queen high or better to open.
+    pub fn qualifies(&self) -> bool {
+        self.category > ThreeCardRank::HighCard || self.cards[0].rank >= Rank::Queen
+    }
+}
+
+/// The bonus paid on the ante for a strong hand, win or lose, as long as the
+/// player plays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AnteBonus {
+    pub straight_flush: u32,
+    pub three_of_a_kind: u32,
+    pub straight: u32,
+}
+
+impl Default for AnteBonus {
+    fn default() -> AnteBonus {
+        AnteBonus {
+            straight_flush: 5,
+            three_of_a_kind: 4,
+            straight: 1,
+        }
+    }
+}
+
+impl AnteBonus {
+    pub fn pays(&self, hand: &ThreeCardHand) -> u32 {
+        match hand.category {
+            ThreeCardRank::StraightFlush => self.straight_flush,
+            ThreeCardRank::ThreeOfAKind => self.three_of_a_kind,
+            ThreeCardRank::Straight => self.straight,
+            _ => 0,
+        }
+    }
+}
+
+/// The pair plus side bet, which pays on the player's hand alone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PairPlus {
+    pub straight_flush: u32,
+    pub three_of_a_kind: u32,
+    pub straight: u32,
+    pub flush: u32,
+    pub pair: u32,
+}
+
+impl Default for PairPlus {
+    fn default() -> PairPlus {
+        PairPlus {
+            straight_flush: 40,
+            three_of_a_kind: 30,
+            straight: 6,
+            flush: 4,
+            pair: 1,
+        }
+    }
+}
+
+impl PairPlus {
+    /// Net result of a one unit bet.
+    pub fn pays(&self, hand: &ThreeCardHand) -> i32 {
+        let pays = match hand.category {
+            ThreeCardRank::StraightFlush => self.straight_flush,
+            ThreeCardRank::ThreeOfAKind => self.three_of_a_kind,
+            ThreeCardRank::Straight => self.straight,
+            ThreeCardRank::Flush => self.flush,
+            ThreeCardRank::Pair => self.pair,
+            ThreeCardRank::HighCard => return -1,
+        };
+        pays as i32
+    }
+
+    /// The house's expected profit per unit bet, over every possible hand.
+    pub fn house_edge(&self) -> f64 {
+        let hands = all_hands();
+        let total: i32 = hands.iter().map(|(_, hand)| self.pays(hand)).sum();
+        -f64::from(total) / hands.len() as f64
+    }
+}
+
+/// Net result, in antes, of a player who plays against the dealer. The play
+/// bet is the same size as the ante. If the dealer doesn't qualify the ante
+/// wins and the play bet is returned.
+pub fn settle_ante(player: &ThreeCardHand, dealer: &ThreeCardHand, bonus: &AnteBonus) -> i32 {
+    bonus.pays(player) as i32 + showdown(player, dealer)
+}
+
+// The ante and play bets against the dealer, without the bonus.
+fn showdown(player: &ThreeCardHand, dealer: &ThreeCardHand) -> i32 {
+    if !dealer.qualifies() {
+        return 1;
+    }
+    match player.cmp(dealer) {
+        Ordering::Greater => 2,
+        Ordering::Equal => 0,
+        Ordering::Less => -2,
+    }
+}
+
+/// The house's expected profit per ante against a player who plays or folds
+/// each hand, whichever is better, worked out over every deal.
+pub fn ante_house_edge(bonus: &AnteBonus) -> f64 {
+    let hands = all_hands();
+    let mut strengths: Vec<u32> = hands.iter().map(|(_, hand)| hand.strength).collect();
+    strengths.sort_unstable();
+    let below = |strength: u32| strengths.partition_point(|&s| s < strength) as i64;
+    let qualifier = hands
+        .iter()
+        .filter(|(_, hand)| hand.qualifies())
+        .map(|(_, hand)| hand.strength)
+        .min()
+        .unwrap_or(0);
+
+    // The hands holding each card, for taking out the dealer hands that
+    // share a card with the player's.
+    let mut holding = vec![vec![]; 52];
+    for (i, (mask, _)) in hands.iter().enumerate() {
+        for (card, hands) in holding.iter_mut().enumerate() {
+            if mask >> card & 1 == 1 {
+                hands.push(i);
+            }
+        }
+    }
+
+    let total = strengths.len() as i64;
+    let mut ev = 0.0;
+    for (mask, player) in &hands {
+        // First against every hand, counted by where it falls...
+        let unqualified = below(qualifier);
+        let mut net = if player.strength < qualifier {
+            unqualified - 2 * (total - unqualified)
+        } else {
+            let lower = below(player.strength) - unqualified;
+            let higher = total - below(player.strength + 1);
+            unqualified + 2 * lower - 2 * higher
+        };
+        // ...then take out those that couldn't be dealt alongside it.
+        let cards: Vec<usize> = (0..52).filter(|card| mask >> card & 1 == 1).collect();
+        for (i, &card) in cards.iter().enumerate() {
+            for &dealer in &holding[card] {
+                let (dealer_mask, dealer) = &hands[dealer];
+                if cards[..i]
+                    .iter()
+                    .all(|&earlier| dealer_mask >> earlier & 1 == 0)
+                {
+                    net -= i64::from(showdown(player, dealer));
+                }
+            }
+        }
+
+        let play = f64::from(bonus.pays(player)) + net as f64 / DEALER_HANDS as f64;
+        ev += play.max(-1.0);
+    }
+
+    -ev / hands.len() as f64
+}
+
+// Every three card hand with a bit set for each of its cards.
+fn all_hands() -> Vec<(u64, ThreeCardHand)> {
+    let deck: Vec<Card> = Card::all().collect();
+    let mut hands = Vec::with_capacity(22100);
+    let mut indices = [0, 1, 2];
+    loop {
+        let mask = indices.iter().fold(0, |mask, &i| mask | 1 << i);
+        hands.push((mask, evaluate_three_card(indices.map(|i| deck[i]))));
+        if !next_combination(&mut indices, deck.len()) {
+            return hands;
+        }
+    }
+}
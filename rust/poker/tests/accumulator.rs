@@ -0,0 +1,86 @@
+#![cfg(feature = "std")]
+
+use poker::{evaluate, parse_cards, Accumulator, Card, Deck, HandRank, Rng};
+
+#[test]
+fn test_add_and_remove() {
+    let cards = parse_cards("AS KH 7D 7C 2S").unwrap();
+    let mut hand = Accumulator::new();
+    assert!(hand.is_empty());
+
+    for &card in &cards {
+        assert!(hand.add(card));
+    }
+    assert!(!hand.add(cards[0]));
+    assert_eq!(hand.len(), 5);
+    assert!(hand.contains(cards[2]));
+
+    assert!(hand.remove(cards[2]));
+    assert!(!hand.remove(cards[2]));
+    assert!(!hand.contains(cards[2]));
+    assert_eq!(hand.len(), 4);
+    assert_eq!(hand.strength(), None);
+    assert_eq!(hand.evaluate(), None);
+
+    hand.add(cards[2]);
+    assert_eq!(hand, cards.iter().copied().collect());
+    assert_eq!(hand.cards(), parse_cards("2S 7D 7C KH AS").unwrap());
+}
+
+#[test]
+fn test_best_hand() {
+    let cases = [
+        ("AS KS QS JS 10S 9S 2H", HandRank::StraightFlush),
+        ("5D 4D 3D 2D AD AS AH", HandRank::StraightFlush),
+        ("9C 9D 9H 9S 2C 2D 2H", HandRank::FourOfAKind),
+        ("KC KD KH QS QC QD 2H", HandRank::FullHouse),
+        ("AH 8H 6H 4H 2H KH KC", HandRank::Flush),
+        ("AS 2D 3C 4H 5S 9D KD", HandRank::Straight),
+        ("7S 7D 7C AH KD 4S 2C", HandRank::ThreeOfAKind),
+        ("AS AD KC KH QS QD 2C", HandRank::TwoPair),
+        ("AS AD KC QH 9S 7D 2C", HandRank::OnePair),
+        ("AS KD QC JH 9S 7D 2C", HandRank::HighCard),
+    ];
+    for (cards, category) in cases {
+        let cards = parse_cards(cards).unwrap();
+        let hand: Accumulator = cards.iter().copied().collect();
+        let expected = evaluate(&cards).unwrap();
+
+        assert_eq!(hand.strength(), Some((category, expected.strength)));
+        assert_eq!(hand.evaluate().unwrap().strength, expected.strength);
+    }
+}
+
+#[test]
+fn test_running_down_boards() {
+    let mut rng = Rng::new(45);
+    for _ in 0..500 {
+        let mut deck = Deck::shuffled(&mut rng);
+        let mut hand = Accumulator::new();
+        let mut dealt: Vec<Card> = vec![];
+
+        // Street by street, as a hold'em hand with two hole cards would go.
+        for _ in 0..7 {
+            let card = deck.deal().unwrap();
+            hand.add(card);
+            dealt.push(card);
+            if dealt.len() < 5 {
+                continue;
+            }
+
+            let expected = evaluate(&dealt).unwrap();
+            let evaluation = hand.evaluate().unwrap();
+            assert_eq!(evaluation.category, expected.category);
+            assert_eq!(evaluation.strength, expected.strength);
+            assert!(evaluation.cards.iter().all(|card| dealt.contains(card)));
+        }
+
+        // Taking the river back off gives the turn's hand again.
+        let river = dealt.pop().unwrap();
+        hand.remove(river);
+        assert_eq!(
+            hand.strength().map(|(_, strength)| strength),
+            evaluate(&dealt).map(|evaluation| evaluation.strength)
+        );
+    }
+}
@@ -0,0 +1,154 @@
+#![cfg(feature = "std")]
+
+use poker::{parse_cards, Board, BoardError, HandRank, Suits};
+
+fn board(cards: &str) -> Board {
+    Board::new(&parse_cards(cards).unwrap()).unwrap()
+}
+
+fn hole(cards: &str) -> [poker::Card; 2] {
+    parse_cards(cards).unwrap().try_into().unwrap()
+}
+
+#[test]
+fn test_board_needs_three_to_five_distinct_cards() {
+    let cards = parse_cards("2S 3S").unwrap();
+    assert_eq!(Board::new(&cards), Err(BoardError::WrongNumberOfCards(2)));
+    let cards = parse_cards("2S 3S 2S").unwrap();
+    assert_eq!(
+        Board::new(&cards),
+        Err(BoardError::DuplicateCard("2S".parse().unwrap()))
+    );
+}
+
+#[test]
+fn test_dry_rainbow_flop() {
+    let texture = board("KS 7D 2C").texture();
+    assert!(!texture.paired);
+    assert_eq!(texture.suits, Suits::Rainbow);
+    assert_eq!(texture.connectedness, 1);
+    assert!(!texture.straight_possible);
+    assert!(!texture.flush_possible);
+    assert_eq!(texture.tags(), ["rainbow"]);
+}
+
+#[test]
+fn test_wet_monotone_flop() {
+    let texture = board("9H 10H JH").texture();
+    assert_eq!(texture.suits, Suits::Monotone);
+    assert_eq!(texture.connectedness, 3);
+    assert!(texture.straight_possible);
+    assert!(texture.flush_possible);
+    assert_eq!(
+        texture.tags(),
+        ["monotone", "straight-possible", "flush-possible"]
+    );
+}
+
+#[test]
+fn test_paired_two_tone_turn() {
+    let texture = board("8S 8D 3S QC").texture();
+    assert!(texture.paired);
+    assert!(!texture.trips);
+    assert_eq!(texture.suits, Suits::TwoTone);
+    let texture = board("8S 8D 3S QD").texture();
+    assert_eq!(texture.suits, Suits::TwoTone);
+    assert_eq!(texture.tags(), ["two-tone", "paired"]);
+    assert_eq!(board("8S 8D 3H QC").texture().suits, Suits::Rainbow);
+}
+
+#[test]
+fn test_wheel_cards_are_connected() {
+    let texture = board("AS 4D 2C").texture();
+    assert_eq!(texture.connectedness, 3);
+    assert!(texture.straight_possible);
+}
+
+#[test]
+fn test_trips_on_the_river() {
+    let texture = board("5S 5D 5C 9H KD").texture();
+    assert!(texture.trips);
+    assert!(texture.tags().contains(&"trips"));
+}
+
+#[test]
+fn test_holdings_exclude_board_and_dead_cards() {
+    let board = board("KS 7D 2C");
+    assert_eq!(board.holdings(&[]).count(), 1176);
+    let dead = parse_cards("AS AD").unwrap();
+    assert_eq!(board.holdings(&dead).count(), 1081);
+}
+
+#[test]
+fn test_nuts() {
+    assert_eq!(board("KS 7D 2C").nuts().category, HandRank::ThreeOfAKind);
+    assert_eq!(board("9H 10H JH").nuts().category, HandRank::StraightFlush);
+    assert_eq!(
+        board("5S 5D 5C 9H KD").nuts().category,
+        HandRank::FourOfAKind
+    );
+}
+
+#[test]
+fn test_combos_beating() {
+    let board = board("KS 7D 2C");
+    // Only the three remaining sets beat top set: 77 and 22, three ways each.
+    assert_eq!(board.combos_beating(&hole("KD KC")), 0);
+    assert_eq!(board.combos_beating(&hole("7S 7C")), 3);
+    // Top pair, top kicker loses to aces, the sets and the two pairs, less
+    // the combos its own ace and king block.
+    let aces = 3;
+    let sets = 1 + 3 + 3;
+    let two_pairs = 2 * 3 + 2 * 3 + 3 * 3;
+    assert_eq!(
+        board.combos_beating(&hole("AS KD")),
+        aces + sets + two_pairs
+    );
+}
+
+#[test]
+fn test_hand_strength_of_the_nuts() {
+    let strength = board("KS 7D 2C").hand_strength(&hole("KD KC")).unwrap();
+    assert_eq!(strength.nut_rank, 1);
+    assert_eq!(strength.hand, strength.nuts);
+    assert!(strength.beaten_by.is_empty());
+    assert_eq!(strength.beats, 1.0);
+    assert_eq!(strength.to_string(), "the nuts, beats 100.0% of combos");
+}
+
+#[test]
+fn test_hand_strength_of_bottom_set() {
+    // Beaten only by the sets of kings and sevens.
+    let strength = board("KS 7D 2C").hand_strength(&hole("2S 2H")).unwrap();
+    assert_eq!(strength.nut_rank, 3);
+    assert_eq!(strength.beaten_by.len(), 3 + 3);
+    assert!(strength.to_string().starts_with("3rd nuts, beats 99."));
+}
+
+#[test]
+fn test_hand_strength_counts_ties() {
+    let strength = board("10C JD QH KC AS")
+        .hand_strength(&hole("2S 3D"))
+        .unwrap();
+    assert_eq!(strength.nut_rank, 1);
+    assert_eq!(strength.ties, 1.0);
+    assert_eq!(strength.beats, 0.0);
+}
+
+#[test]
+fn test_hand_strength_lists_better_holdings() {
+    let board = board("KS 7D 2C");
+    let strength = board.hand_strength(&hole("AS KD")).unwrap();
+    assert_eq!(
+        strength.beaten_by.len(),
+        board.combos_beating(&hole("AS KD"))
+    );
+    assert!(strength.beaten_by.contains(&hole("AH AD")));
+    assert!(!strength.beaten_by.contains(&hole("AS AD")));
+    assert!(strength.nut_rank > 3);
+}
+
+#[test]
+fn test_hand_strength_rejects_board_cards() {
+    assert!(board("KS 7D 2C").hand_strength(&hole("KS KD")).is_none());
+}
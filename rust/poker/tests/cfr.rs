@@ -0,0 +1,114 @@
+#![cfg(feature = "std")]
+
+use poker::{
+    expected_value, exploitability, Cfr, GameTree, HeadsUpEquities, Kuhn, Leduc, Node, PushFold,
+    StartingHand,
+};
+
+fn hand(name: &str) -> StartingHand {
+    StartingHand::all()
+        .into_iter()
+        .find(|hand| hand.to_string() == name)
+        .unwrap()
+}
+
+#[test]
+fn test_kuhn_converges_to_the_game_value() {
+    let mut cfr = Cfr::new(Kuhn);
+    cfr.run(1000);
+    let strategy = cfr.strategy();
+
+    assert_eq!(cfr.iterations(), 1000);
+    assert_eq!(strategy.len(), 12);
+    assert!((expected_value(&Kuhn, &strategy) + 1.0 / 18.0).abs() < 0.001);
+    assert!(cfr.exploitability() < 0.001);
+
+    // The king always calls a bet and the jack never does.
+    assert!(strategy.probability(&"Kpb".to_string(), "call").unwrap() > 0.99);
+    assert!(strategy.probability(&"Jb".to_string(), "call").unwrap() < 0.01);
+    assert_eq!(strategy.probability(&"Kpb".to_string(), "bet"), None);
+}
+
+#[test]
+fn test_uniform_strategy_is_exploitable() {
+    let cfr = Cfr::new(Kuhn);
+    let strategy = cfr.strategy();
+    assert!(strategy.is_empty());
+    assert!(exploitability(&Kuhn, &strategy) > 0.1);
+}
+
+#[test]
+fn test_kuhn_tree() {
+    let Node::Chance(deals) = Kuhn.node(&Kuhn.root()) else {
+        panic!("the cards are dealt first");
+    };
+    assert_eq!(deals.len(), 6);
+    assert!((deals.iter().map(|(p, _)| p).sum::<f64>() - 1.0).abs() < 1e-12);
+
+    let Node::Decision {
+        player, actions, ..
+    } = Kuhn.node(&deals[0].1)
+    else {
+        panic!("the first player acts after the deal");
+    };
+    assert_eq!(player, 0);
+    let names: Vec<&str> = actions.iter().map(|&(name, _)| name).collect();
+    assert_eq!(names, ["check", "bet"]);
+}
+
+#[test]
+fn test_leduc_converges_to_the_game_value() {
+    let mut cfr = Cfr::new(Leduc);
+    cfr.run(200);
+    let strategy = cfr.strategy();
+
+    assert_eq!(strategy.len(), 288);
+    assert!((expected_value(&Leduc, &strategy) + 0.0856).abs() < 0.01);
+    assert!(cfr.exploitability() < 0.02);
+    // Nobody folds the best hand on the river.
+    assert!(
+        strategy
+            .probability(&"KK:cc/r".to_string(), "fold")
+            .unwrap()
+            < 0.02
+    );
+}
+
+#[test]
+fn test_push_fold_ranges() {
+    let equities = HeadsUpEquities::estimate(10, 0).unwrap();
+    assert!(equities.equity(hand("AA"), hand("72o")).unwrap() > 0.7);
+
+    let mut short = Cfr::new(PushFold::new(2.0, equities.clone()));
+    short.run(20);
+    let mut deep = Cfr::new(PushFold::new(25.0, equities));
+    deep.run(20);
+    let (short_strategy, deep_strategy) = (short.strategy(), deep.strategy());
+
+    for strategy in [&short_strategy, &deep_strategy] {
+        assert!(strategy.probability(&(0, hand("AA")), "push").unwrap() > 0.95);
+        assert!(strategy.probability(&(1, hand("AA")), "call").unwrap() > 0.95);
+    }
+    assert!(short.exploitability() < 0.01);
+    assert!(deep.exploitability() < 0.01);
+
+    // Short stacks push nearly everything, deeper ones get tighter, and
+    // the big blind calls tighter still.
+    let (short_push, deep_push) = (
+        short.game().range(&short_strategy, 0),
+        deep.game().range(&deep_strategy, 0),
+    );
+    assert!(short_push > 0.8);
+    assert!(deep_push < 0.5);
+    assert!(deep.game().range(&deep_strategy, 1) < deep_push);
+    assert!(
+        deep_strategy
+            .probability(&(0, hand("72o")), "push")
+            .unwrap()
+            < 0.05
+    );
+
+    let chart = deep.game().chart(&deep_strategy, 0);
+    assert_eq!(chart.lines().count(), 14);
+    assert!(chart.lines().nth(1).unwrap().starts_with("   A  100"));
+}
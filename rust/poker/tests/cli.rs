@@ -0,0 +1,77 @@
+#![cfg(feature = "std")]
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn poker(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_poker"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+#[test]
+fn test_winner_from_args() {
+    let output = poker(&["4S 5S 7H 8D JC", "2S 4H 6S 4D JH"], "");
+    assert!(output.status.success());
+    let stdout = stdout(&output);
+    assert!(stdout.contains("4S 5S 7H 8D JC: high card, jack high"));
+    assert!(stdout.contains("2S 4H 6S 4D JH: pair of fours"));
+    assert!(stdout.contains("winner: 2S 4H 6S 4D JH"));
+    assert!(!stdout.contains("winner: 4S 5S 7H 8D JC"));
+}
+
+#[test]
+fn test_winners_from_stdin() {
+    let output = poker(&[], "3S 4S 5D 6H JH\n\n3H 4H 5C 6C JD\n");
+    assert!(output.status.success());
+    let stdout = stdout(&output);
+    assert!(stdout.contains("winner: 3S 4S 5D 6H JH"));
+    assert!(stdout.contains("winner: 3H 4H 5C 6C JD"));
+}
+
+#[test]
+fn test_equity_with_board_and_dead_cards() {
+    let output = poker(
+        &["--board", "2H 7H KC QD", "--dead", "3C", "AH 4H", "KD KS"],
+        "",
+    );
+    assert!(output.status.success());
+    let stdout = stdout(&output);
+    assert!(stdout.contains("AH 4H: equity"));
+    assert!(stdout.contains("KD KS: equity"));
+    assert!(stdout.contains("three of a kind, kings"));
+}
+
+#[test]
+fn test_malformed_hand_fails() {
+    let output = poker(&["4S 5S 7H 8D 1C"], "");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("malformed hand"));
+}
+
+#[test]
+fn test_wrong_number_of_cards_fails() {
+    assert!(!poker(&["4S 5S 7H 8D"], "").status.success());
+}
+
+#[test]
+fn test_unknown_option_fails() {
+    assert!(!poker(&["--bored", "2H 7H KC", "AH 4H"], "")
+        .status
+        .success());
+}
@@ -0,0 +1,85 @@
+#![cfg(feature = "std")]
+
+use poker::{
+    choose, parse_cards, remaining_cards, runouts, Card, Combinations, Rank, StartingHand,
+};
+
+fn cards(cards: &str) -> Vec<Card> {
+    parse_cards(cards).unwrap()
+}
+
+#[test]
+fn test_choose() {
+    assert_eq!(choose(52, 2), 1326);
+    assert_eq!(choose(52, 5), 2_598_960);
+    assert_eq!(choose(52, 7), 133_784_560);
+    assert_eq!(choose(48, 5), 1_712_304);
+    assert_eq!(choose(5, 0), 1);
+    assert_eq!(choose(2, 3), 0);
+}
+
+#[test]
+fn test_combinations_around_dead_cards() {
+    let dead = cards("AS KS QS");
+    let remaining = remaining_cards(&dead);
+    assert_eq!(remaining.len(), 49);
+    assert!(dead.iter().all(|card| !remaining.contains(card)));
+
+    let holdings: Vec<Vec<Card>> = Combinations::remaining(&dead, 2).collect();
+    assert_eq!(holdings.len() as u64, choose(49, 2));
+    assert!(holdings
+        .iter()
+        .all(|holding| holding.len() == 2 && holding[0] != holding[1]));
+    assert!(holdings.iter().flatten().all(|card| !dead.contains(card)));
+
+    assert_eq!(Combinations::new(cards("2S 3S"), 3).count(), 0);
+    assert_eq!(
+        Combinations::new(cards("2S 3S 4S"), 2).collect::<Vec<_>>(),
+        vec![cards("2S 3S"), cards("2S 4S"), cards("3S 4S")]
+    );
+}
+
+#[test]
+fn test_runouts() {
+    let board = cards("2H 7D KC");
+    let dead = cards("AS AD");
+    let turns_and_rivers: Vec<Vec<Card>> = runouts(&board, &dead).collect();
+    assert_eq!(turns_and_rivers.len() as u64, choose(47, 2));
+    assert!(turns_and_rivers
+        .iter()
+        .flatten()
+        .all(|card| !board.contains(card) && !dead.contains(card)));
+
+    assert_eq!(runouts(&[], &dead).count() as u64, choose(50, 5));
+    let river = cards("2H 7D KC 9S 3C");
+    assert_eq!(runouts(&river, &[]).collect::<Vec<_>>(), vec![vec![]]);
+}
+
+#[test]
+fn test_live_combos() {
+    let aces = StartingHand {
+        high: Rank::Ace,
+        low: Rank::Ace,
+        suited: false,
+    };
+    let ace_king_suited = StartingHand {
+        high: Rank::Ace,
+        low: Rank::King,
+        suited: true,
+    };
+    let ace_king_offsuit = StartingHand {
+        suited: false,
+        ..ace_king_suited
+    };
+
+    assert_eq!(aces.live_combos(&[]), 6);
+    assert_eq!(aces.live_combos(&cards("AS")), 3);
+    assert_eq!(aces.live_combos(&cards("AS AH AD")), 0);
+    assert_eq!(ace_king_suited.live_combos(&cards("AS 2C")), 3);
+    // Each blocker takes out three combos, one of which they share.
+    assert_eq!(ace_king_offsuit.live_combos(&cards("AS KH")), 7);
+    assert!(ace_king_offsuit
+        .live_holdings(&cards("AS KH"))
+        .iter()
+        .all(|holding| holding[0] != cards("AS")[0] && holding[1] != cards("KH")[0]));
+}
@@ -0,0 +1,153 @@
+#![cfg(feature = "std")]
+
+use poker::{
+    evaluate, evaluate_with, parse_cards, winning_hands, Card, Composition, Deck, Evaluation,
+    HandRank, Rank, Rng,
+};
+
+fn eval(hand: &str, composition: &Composition) -> Evaluation {
+    evaluate_with(&parse_cards(hand).unwrap(), composition).unwrap()
+}
+
+#[test]
+fn test_deck_sizes() {
+    assert_eq!(Composition::standard().len(), 52);
+    assert_eq!(Composition::standard().decks(2).len(), 104);
+    assert_eq!(Composition::short_deck().len(), 36);
+    assert_eq!(Composition::spanish().len(), 48);
+    assert_eq!(Composition::spanish().without(Rank::Nine).len(), 44);
+    assert_eq!(Composition::stripped(Rank::Seven).len(), 32);
+}
+
+#[test]
+fn test_deck_from_composition() {
+    let composition = Composition::short_deck().decks(2);
+    let deck = Deck::with_composition(&composition);
+    assert_eq!(deck.len(), 72);
+    assert!(deck.cards().iter().all(|card| card.rank >= Rank::Six));
+
+    let ace: Card = "AS".parse().unwrap();
+    assert_eq!(deck.cards().iter().filter(|&&card| card == ace).count(), 2);
+}
+
+#[test]
+fn test_remove_takes_one_copy_per_card() {
+    let mut deck = Deck::with_composition(&Composition::standard().decks(2));
+    let ace: Card = "AS".parse().unwrap();
+    deck.remove(&[ace]);
+    assert_eq!(deck.len(), 103);
+    assert!(deck.cards().contains(&ace));
+
+    deck.remove(&[ace]);
+    assert!(!deck.cards().contains(&ace));
+}
+
+#[test]
+fn test_standard_composition_matches_evaluate() {
+    let mut rng = Rng::new(34);
+    let standard = Composition::standard();
+    for _ in 0..200 {
+        let mut deck = Deck::shuffled(&mut rng);
+        let cards: Vec<Card> = (0..7).filter_map(|_| deck.deal()).collect();
+        assert_eq!(evaluate(&cards), evaluate_with(&cards, &standard));
+    }
+}
+
+#[test]
+fn test_five_of_a_kind() {
+    let two_decks = Composition::standard().decks(2);
+    let hand = eval("9S 9H 9D 9C 9S", &two_decks);
+    assert_eq!(hand.category, HandRank::FiveOfAKind);
+    assert_eq!(hand.description(), "five of a kind, nines");
+    assert!(hand > eval("10H JH QH KH AH", &two_decks));
+    assert!(hand < eval("10S 10H 10D 10C 10S", &two_decks));
+}
+
+#[test]
+fn test_flush_five() {
+    let hand = eval("2H 2H 2H 2H 2H", &Composition::standard().decks(5));
+    assert_eq!(hand.category, HandRank::FlushFive);
+    assert_eq!(hand.description(), "flush five, twos");
+    assert!(hand > eval("AS AH AD AC AS", &Composition::standard().decks(5)));
+}
+
+#[test]
+fn test_flush_with_a_pair_is_a_flush() {
+    let two_decks = Composition::standard().decks(2);
+    let hand = eval("KH KH 9H 6H 3H", &two_decks);
+    assert_eq!(hand.category, HandRank::Flush);
+    assert!(hand > eval("KH QH 9H 6H 3H", &two_decks));
+    assert!(hand < eval("AH 2H 3H 4H 6H", &two_decks));
+}
+
+#[test]
+fn test_one_deck_ignores_repeated_cards() {
+    let hands = ["4S 4H 4S 4D 4H", "3S 4S 5S 6S 7S"];
+    assert_eq!(winning_hands(&hands), vec!["3S 4S 5S 6S 7S"]);
+    let hands = ["KH KH 9H 6H 3H", "2C 3D 4H 5S 7C"];
+    assert_eq!(winning_hands(&hands), vec!["2C 3D 4H 5S 7C"]);
+
+    let standard = Composition::standard();
+    let cards = |cards: &str| parse_cards(cards).unwrap();
+    assert_eq!(evaluate_with(&cards("4S 4H 4S 4D 4H"), &standard), None);
+    assert_eq!(evaluate_with(&cards("KH KH 9H 6H 3H"), &standard), None);
+    assert_eq!(
+        evaluate_with(&cards("KH KH 9H 6H 3H 2H"), &standard).map(|hand| hand.category),
+        Some(HandRank::Flush)
+    );
+}
+
+#[test]
+fn test_ace_high_straight_without_kings() {
+    let no_kings = Composition::standard().without(Rank::King);
+    let straight = eval("AS QH JD 10C 9S", &no_kings);
+    assert_eq!(straight.category, HandRank::Straight);
+    assert_eq!(straight.description(), "straight, ace high");
+    assert!(straight > eval("QH JD 10C 9S 8S", &no_kings));
+    assert!(eval("AS 2H 3D 4C 5S", &no_kings) < eval("2H 3D 4C 5S 6S", &no_kings));
+}
+
+#[test]
+fn test_short_deck_wheel() {
+    let short = Composition::short_deck();
+    let wheel = eval("AS 6H 7D 8C 9S", &short);
+    assert_eq!(wheel.category, HandRank::Straight);
+    assert_eq!(wheel.description(), "straight, nine high");
+    assert!(wheel < eval("6H 7D 8C 9S 10S", &short));
+    assert!(wheel > eval("AS AH KD QC JS", &short));
+
+    // In a full deck it's just ace high.
+    assert_eq!(
+        eval("AS 6H 7D 8C 9S", &Composition::standard()).category,
+        HandRank::HighCard
+    );
+}
+
+#[test]
+fn test_short_deck_flush_beats_full_house() {
+    let short = Composition::short_deck();
+    let flush = eval("6H 8H 10H QH KH", &short);
+    let full_house = eval("AS AH AD KC KS", &short);
+    assert!(flush > full_house);
+
+    let standard = Composition::standard();
+    assert!(eval("6H 8H 10H QH KH", &standard) < eval("AS AH AD KC KS", &standard));
+}
+
+#[test]
+fn test_spanish_straights_skip_the_tens() {
+    let spanish = Composition::spanish();
+    assert_eq!(
+        eval("8S 9H JD QC KS", &spanish).category,
+        HandRank::Straight
+    );
+    assert_eq!(
+        eval("9S JH QD KC AS", &spanish).description(),
+        "straight, ace high"
+    );
+    assert!(eval("9S JH QD KC AS", &spanish) > eval("8S 9H JD QC KS", &spanish));
+    assert_eq!(
+        eval("8S 9H JD QC KS", &Composition::standard()).category,
+        HandRank::HighCard
+    );
+}
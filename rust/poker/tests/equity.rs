@@ -0,0 +1,64 @@
+#![cfg(feature = "std")]
+
+use poker::{equity, parse_cards, EquityError};
+
+fn holdings(hands: &[&str]) -> Vec<Vec<poker::Card>> {
+    hands
+        .iter()
+        .map(|hand| parse_cards(hand).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_complete_board_has_a_single_runout() {
+    let board = parse_cards("2S 7D 9C JH KS").unwrap();
+    let result = equity(&holdings(&["AS AD", "QH QC"]), &board, &[]).unwrap();
+    assert_eq!(result[0].win, 1.0);
+    assert_eq!(result[1].equity, 0.0);
+}
+
+#[test]
+fn test_board_plays_for_a_split_pot() {
+    let board = parse_cards("10S JS QS KS AS").unwrap();
+    let result = equity(&holdings(&["2D 3C", "4H 5H"]), &board, &[]).unwrap();
+    assert_eq!(result[0].tie, 1.0);
+    assert_eq!(result[0].equity, 0.5);
+    assert_eq!(result[1].equity, 0.5);
+}
+
+#[test]
+fn test_flush_draw_on_the_flop() {
+    // Nine flush outs twice, less the overlap: the textbook 35%.
+    let board = parse_cards("2H 7H KC").unwrap();
+    let result = equity(&holdings(&["AH 4H", "KD KS"]), &board, &[]).unwrap();
+    let total: f64 = result.iter().map(|equity| equity.equity).sum();
+    assert!((total - 1.0).abs() < 1e-9);
+    assert!((0.25..0.4).contains(&result[0].equity));
+}
+
+#[test]
+fn test_dead_cards_are_removed_from_the_runouts() {
+    // With every remaining heart dead, the flush draw can only win by pairing.
+    let board = parse_cards("2H 7H KC QD").unwrap();
+    let dead = parse_cards("3H 5H 6H 8H 9H 10H JH QH KH").unwrap();
+    let result = equity(&holdings(&["AH 4H", "KD KS"]), &board, &dead).unwrap();
+    assert_eq!(result[0].equity, 0.0);
+}
+
+#[test]
+fn test_duplicate_cards_are_rejected() {
+    let board = parse_cards("2H 7H KC").unwrap();
+    assert_eq!(
+        equity(&holdings(&["AH 2H", "KD KS"]), &board, &[]),
+        Err(EquityError::DuplicateCard("2H".parse().unwrap()))
+    );
+}
+
+#[test]
+fn test_oversized_board_is_rejected() {
+    let board = parse_cards("2H 3H 4H 5H 6H 7H").unwrap();
+    assert_eq!(
+        equity(&holdings(&["AS AD"]), &board, &[]),
+        Err(EquityError::TooManyBoardCards(6))
+    );
+}
@@ -0,0 +1,194 @@
+#![cfg(feature = "std")]
+
+use poker::{
+    evaluate, parse_cards, winning_hands, Cached, Card, Deck, Evaluator, Exhaustive, HandRank,
+    Incremental, ParseCardError, Rank, Rng, Suit,
+};
+
+fn eval(hand: &str) -> poker::Evaluation {
+    evaluate(&parse_cards(hand).unwrap()).unwrap()
+}
+
+#[test]
+fn test_parse_card() {
+    assert_eq!("10H".parse(), Ok(Card::new(Rank::Ten, Suit::Hearts)));
+    assert_eq!("AS".parse(), Ok(Card::new(Rank::Ace, Suit::Spades)));
+}
+
+#[test]
+fn test_parse_invalid_cards() {
+    assert_eq!(
+        "1H".parse::<Card>(),
+        Err(ParseCardError::InvalidRank("1H".to_string()))
+    );
+    assert_eq!(
+        "KX".parse::<Card>(),
+        Err(ParseCardError::InvalidSuit("KX".to_string()))
+    );
+    assert!("".parse::<Card>().is_err());
+}
+
+#[test]
+fn test_card_display_round_trips() {
+    for hand in ["2S 3H 4D 5C 6S", "10H JD QC KS AH"] {
+        let cards = parse_cards(hand).unwrap();
+        let shown: Vec<String> = cards.iter().map(Card::to_string).collect();
+        assert_eq!(shown.join(" "), hand);
+    }
+}
+
+#[test]
+fn test_evaluate_needs_five_cards() {
+    assert!(evaluate(&parse_cards("2S 3H 4D 5C").unwrap()).is_none());
+}
+
+#[test]
+fn test_evaluate_categories() {
+    assert_eq!(eval("4S 5S 7H 8D JC").category, HandRank::HighCard);
+    assert_eq!(eval("2S 4H 6S 4D JH").category, HandRank::OnePair);
+    assert_eq!(eval("4S 5H 4C 8C 5C").category, HandRank::TwoPair);
+    assert_eq!(eval("4S 5H 4C 8D 4H").category, HandRank::ThreeOfAKind);
+    assert_eq!(eval("4D AH 3S 2D 5C").category, HandRank::Straight);
+    assert_eq!(eval("2S 4S 5S 6S 7S").category, HandRank::Flush);
+    assert_eq!(eval("4H 4S 4D 9S 9D").category, HandRank::FullHouse);
+    assert_eq!(eval("3S 3H 2S 3D 3C").category, HandRank::FourOfAKind);
+    assert_eq!(eval("7S 8S 9S 6S 10S").category, HandRank::StraightFlush);
+}
+
+#[test]
+fn test_evaluation_orders_cards_by_significance() {
+    let cards = eval("4S 5H 4C 8C 5C").cards.map(|card| card.to_string());
+    assert_eq!(cards, ["5H", "5C", "4S", "4C", "8C"]);
+}
+
+#[test]
+fn test_ace_plays_last_in_a_five_high_straight() {
+    let cards = eval("4D AH 3S 2D 5C").cards.map(|card| card.rank);
+    assert_eq!(
+        cards,
+        [Rank::Five, Rank::Four, Rank::Three, Rank::Two, Rank::Ace]
+    );
+}
+
+#[test]
+fn test_strength_orders_hands() {
+    assert!(eval("4D AH 3S 2D 5C") < eval("4S 6C 7S 8D 5H"));
+    assert!(eval("2S 8H 2D 8D 3H") > eval("4S 5H 4C 8S 5D"));
+    assert!(eval("3S 5H 6S 8D 7H") > eval("2S 5D 6D 8C 7S"));
+    assert_eq!(
+        eval("4S 5H 4C 8C 5C").strength,
+        eval("4H 5D 4D 8S 5S").strength
+    );
+}
+
+#[test]
+fn test_evaluate_picks_best_five_of_seven() {
+    let evaluation = eval("2S 9H KD 10S 3H JS QS");
+    assert_eq!(evaluation.category, HandRank::Straight);
+    assert_eq!(evaluation.description(), "straight, king high");
+}
+
+#[test]
+fn test_descriptions() {
+    assert_eq!(
+        eval("4H 4S 4D 9S 9D").description(),
+        "full house, fours over nines"
+    );
+    assert_eq!(
+        eval("4S 5H 4C 8C 5C").description(),
+        "two pair, fives and fours"
+    );
+    assert_eq!(eval("10S JS QS KS AS").description(), "royal flush");
+}
+
+#[test]
+fn test_winning_hands_low_pair_ranks() {
+    // Pairs below two other ranks used to be scored as the wrong rank.
+    let hands = ["4H 5S 5H 7S 10S", "4S 5D 6D 7C 7H"];
+    assert_eq!(winning_hands(&hands), vec!["4S 5D 6D 7C 7H"]);
+    let hands = ["2H 3S 3H 7S 10S", "2S 3D 4D 7C 10C"];
+    assert_eq!(winning_hands(&hands), vec!["2H 3S 3H 7S 10S"]);
+}
+
+#[test]
+fn test_winning_hands_high_trips() {
+    let hands = ["6H JH JD JC AD", "6S QD AS AH AC"];
+    assert_eq!(winning_hands(&hands), vec!["6S QD AS AH AC"]);
+}
+
+#[test]
+fn test_winning_hands_ignores_garbage() {
+    assert_eq!(winning_hands(&["", "not a hand"]), vec!["", "not a hand"]);
+    assert_eq!(
+        winning_hands(&["2S 2H 2D 2C 2S 2H", "3S 4S 5S 6S 8D"]),
+        vec!["3S 4S 5S 6S 8D"]
+    );
+}
+
+#[test]
+fn test_evaluators_agree() {
+    let mut rng = Rng::new(46);
+    for size in 5..=9 {
+        for _ in 0..200 {
+            let mut deck = Deck::shuffled(&mut rng);
+            let cards: Vec<Card> = (0..size).map(|_| deck.deal().unwrap()).collect();
+            assert_eq!(
+                Incremental.strength(&cards),
+                Exhaustive.strength(&cards),
+                "{cards:?}"
+            );
+        }
+    }
+    assert_eq!(Incremental.strength(&parse_cards("AS KS").unwrap()), None);
+}
+
+#[test]
+fn test_cached_evaluator() {
+    let mut rng = Rng::new(50);
+    let hands: Vec<Vec<Card>> = (0..50)
+        .map(|_| {
+            let mut deck = Deck::shuffled(&mut rng);
+            (0..7).map(|_| deck.deal().unwrap()).collect()
+        })
+        .collect();
+
+    let mut cached = Cached::new(Exhaustive, 100);
+    for _ in 0..3 {
+        for hand in &hands {
+            assert_eq!(cached.strength(hand), Exhaustive.strength(hand));
+        }
+    }
+    assert_eq!((cached.misses(), cached.hits()), (50, 100));
+    assert_eq!(cached.len(), 50);
+
+    // The same cards in another order are the same hand.
+    let mut reversed = hands[0].clone();
+    reversed.reverse();
+    cached.strength(&reversed);
+    assert_eq!(cached.hits(), 101);
+    assert_eq!(cached.strength(&hands[0][..4]), None);
+}
+
+#[test]
+fn test_cached_evaluator_drops_least_recently_used() {
+    let [a, b, c] = ["AS KS QS JS 10S", "2C 3D 4H 5S 7C", "9H 9D 9C 9S 2H"]
+        .map(|hand| parse_cards(hand).unwrap());
+    let mut cached = Cached::new(Incremental, 2);
+    cached.strength(&a);
+    cached.strength(&b);
+    cached.strength(&a);
+    // Full, so this pushes out b, which has waited longest.
+    cached.strength(&c);
+    assert_eq!(cached.len(), 2);
+    assert_eq!(cached.misses(), 3);
+
+    cached.strength(&a);
+    cached.strength(&c);
+    assert_eq!(cached.misses(), 3);
+    assert_eq!(cached.strength(&b), Incremental.strength(&b));
+    assert_eq!(cached.misses(), 4);
+
+    cached.clear();
+    assert!(cached.is_empty());
+    assert_eq!(cached.capacity(), 2);
+}
@@ -0,0 +1,336 @@
+#![cfg(feature = "std")]
+
+use poker::{
+    parse_cards, parse_histories, ActionKind, AwardError, Card, HandHistory, HandRank,
+    HistoryAction, HistoryError, Rank, Site, Street,
+};
+
+const STARS_SHOWDOWN: &str = "\
+PokerStars Hand #254446595385:  Hold'em No Limit ($0.02/$0.05 USD) - 2024/01/05 12:34:56 ET
+Table 'Aaltje III' 6-max Seat #3 is the button
+Seat 1: player1 ($5.00 in chips)
+Seat 2: player2 ($4.87 in chips)
+Seat 3: hero ($5.12 in chips)
+player1: posts small blind $0.02
+player2: posts big blind $0.05
+*** HOLE CARDS ***
+Dealt to hero [Ah Kd]
+hero: raises $0.10 to $0.15
+player1: folds
+player2: calls $0.10
+*** FLOP *** [2c 7d Ks]
+player2: checks
+hero: bets $0.20
+player2: calls $0.20
+*** TURN *** [2c 7d Ks] [9h]
+player2: checks
+hero: checks
+*** RIVER *** [2c 7d Ks 9h] [Qc]
+player2: bets $0.40
+hero: calls $0.40
+*** SHOW DOWN ***
+player2: shows [Qd Qs] (three of a kind, Queens)
+hero: shows [Ah Kd] (a pair of Kings)
+player2 collected $1.45 from pot
+*** SUMMARY ***
+Total pot $1.52 | Rake $0.07
+Board [2c 7d Ks 9h Qc]
+Seat 1: player1 (small blind) folded before Flop
+Seat 2: player2 (big blind) showed [Qd Qs] and won ($1.45) with three of a kind, Queens
+Seat 3: hero (button) showed [Ah Kd] and lost with a pair of Kings
+";
+
+const STARS_UNCALLED: &str = "\
+PokerStars Hand #254446595386:  Hold'em No Limit ($0.02/$0.05 USD) - 2024/01/05 12:35:40 ET
+Table 'Aaltje III' 6-max Seat #1 is the button
+Seat 1: player1 ($4.98 in chips)
+Seat 2: player2 ($5.60 in chips)
+Seat 3: hero ($4.57 in chips)
+Seat 4: sleepy ($3.00 in chips) is sitting out
+player2: posts small blind $0.02
+hero: posts big blind $0.05
+*** HOLE CARDS ***
+Dealt to hero [7c 2d]
+player1: raises $0.10 to $0.15
+player2: folds
+hero said, \"nh\"
+hero: folds
+Uncalled bet ($0.10) returned to player1
+player1 collected $0.12 from pot
+player1: doesn't show hand
+*** SUMMARY ***
+Total pot $0.12 | Rake $0
+Seat 1: player1 (button) collected ($0.12)
+Seat 2: player2 (small blind) folded before Flop
+Seat 3: hero (big blind) folded before Flop
+";
+
+const STARS_SIDE_POT: &str = "\
+PokerStars Hand #254446600001: Tournament #3700000001, $1.00+$0.10 USD Hold'em No Limit - Level II (15/30) - 2024/01/05 13:00:00 ET
+Table '3700000001 1' 9-max Seat #1 is the button
+Seat 1: alice (400 in chips)
+Seat 2: bob (1000 in chips)
+Seat 3: carol (1500 in chips)
+bob: posts small blind 15
+carol: posts big blind 30
+*** HOLE CARDS ***
+alice: raises 370 to 400 and is all-in
+bob: raises 600 to 1000 and is all-in
+carol: calls 970
+*** FLOP *** [2c 7d 8s]
+*** TURN *** [2c 7d 8s] [9h]
+*** RIVER *** [2c 7d 8s 9h] [3s]
+*** SHOW DOWN ***
+bob: shows [Kd Kh] (a pair of Kings)
+carol: shows [Qd Qs] (a pair of Queens)
+bob collected 1200 from side pot
+alice: shows [Ac Ad] (a pair of Aces)
+alice collected 1200 from main pot
+*** SUMMARY ***
+Total pot 2400 Main pot 1200. Side pot 1200. | Rake 0
+Board [2c 7d 8s 9h 3s]
+";
+
+const GG_SPLIT_POT: &str = "\
+Poker Hand #RC1800000001: Hold'em No Limit ($0.01/$0.02) - 2024/01/06 20:15:03
+Table 'RushAndCash1234' 6-max Seat #1 is the button
+Seat 1: 5a1b2c ($2.00 in chips)
+Seat 2: Hero ($2.10 in chips)
+Seat 3: 9f8e7d ($1.50 in chips)
+Hero: posts small blind $0.01
+9f8e7d: posts big blind $0.02
+*** HOLE CARDS ***
+Dealt to 5a1b2c
+Dealt to Hero [Th Jh]
+Dealt to 9f8e7d
+5a1b2c: calls $0.02
+Hero: calls $0.01
+9f8e7d: checks
+*** FLOP *** [Ad Kc Qs]
+Hero: bets $0.04
+9f8e7d: folds
+5a1b2c: calls $0.04
+*** TURN *** [Ad Kc Qs] [2h]
+Hero: checks
+5a1b2c: checks
+*** RIVER *** [Ad Kc Qs 2h] [3c]
+Hero: checks
+5a1b2c: checks
+*** SHOWDOWN ***
+Hero: shows [Th Jh] (a straight, Ten to Ace)
+5a1b2c: shows [Tc Jd] (a straight, Ten to Ace)
+Hero collected $0.07 from pot
+5a1b2c collected $0.06 from pot
+*** SUMMARY ***
+Total pot $0.14 | Rake $0.01 | Jackpot $0 | Bingo $0 | Fortune $0 | Tax $0
+Board [Ad Kc Qs 2h 3c]
+";
+
+const WINAMAX: &str = "\
+Winamax Poker - CashGame - HandId: #18876587-492053-1695486636 - Holdem no limit (0.01€/0.02€) - 2023/09/23 16:30:36 UTC
+Table: 'Nice 05' 5-max (real money) Seat #2 is the button
+Seat 1: Big Blinder (2€)
+Seat 2: hero (1.99€)
+*** ANTE/BLINDS ***
+hero posts small blind 0.01€
+Big Blinder posts big blind 0.02€
+Dealt to hero [8s 8d]
+*** PRE-FLOP ***
+hero raises 0.04€ to 0.06€
+Big Blinder calls 0.04€
+*** FLOP *** [2c 7d Ks]
+Big Blinder checks
+hero bets 0.06€
+Big Blinder calls 0.06€
+*** TURN *** [2c 7d Ks][9h]
+Big Blinder checks
+hero checks
+*** RIVER *** [2c 7d Ks 9h][Qc]
+Big Blinder checks
+hero checks
+*** SHOW DOWN ***
+Big Blinder shows [Kh 4h] (One pair : Kings)
+hero shows [8s 8d] (One pair : 8)
+Big Blinder collected 0.23€ from pot
+*** SUMMARY ***
+Total pot 0.24€ | Rake 0.01€
+Board: [2c 7d Ks 9h Qc]
+Seat 1: Big Blinder (big blind) showed [Kh 4h] and won 0.23€ with One pair : Kings
+Seat 2: hero (small blind) showed [8s 8d] and lost with One pair : 8
+";
+
+fn hand(text: &str) -> HandHistory {
+    text.parse().unwrap()
+}
+
+fn hole(cards: &str) -> Option<[Card; 2]> {
+    let cards = parse_cards(cards).unwrap();
+    Some([cards[0], cards[1]])
+}
+
+#[test]
+fn test_pokerstars_showdown() {
+    let hand = hand(STARS_SHOWDOWN);
+    assert_eq!(hand.site, Site::PokerStars);
+    assert_eq!(hand.id, "254446595385");
+    assert_eq!(hand.table, "Aaltje III");
+    assert_eq!((hand.small_blind, hand.big_blind), (2, 5));
+    assert_eq!(hand.button, 2);
+    assert_eq!(hand.seats.len(), 3);
+    assert_eq!(hand.seats[1].stack, 487);
+    assert_eq!(hand.seats[2].hole, hole("AH KD"));
+    assert_eq!(hand.seats[1].hole, hole("QD QS"));
+    assert_eq!(hand.board, parse_cards("2C 7D KS 9H QC").unwrap());
+    assert_eq!(hand.rake, 7);
+
+    assert_eq!(hand.actions.len(), 12);
+    assert_eq!(
+        hand.actions[2],
+        HistoryAction {
+            street: Street::Preflop,
+            seat: 2,
+            kind: ActionKind::Raise(15)
+        }
+    );
+    assert_eq!(hand.actions[11].street, Street::River);
+
+    assert_eq!(hand.pot(), 152);
+    assert_eq!(hand.net(), vec![-2, 70, -75]);
+    assert_eq!(hand.verify(), Ok(()));
+}
+
+#[test]
+fn test_uncalled_bets_are_returned() {
+    let hand = hand(STARS_UNCALLED);
+    assert_eq!(hand.seats.len(), 4);
+    assert_eq!(hand.committed(), vec![5, 2, 5, 0]);
+    assert_eq!(hand.awards(), Ok(vec![12, 0, 0, 0]));
+    assert_eq!(hand.net(), vec![7, -2, -5, 0]);
+    assert_eq!(hand.showdown(), Ok(vec![]));
+    assert_eq!(hand.verify(), Ok(()));
+}
+
+#[test]
+fn test_player_named_like_the_table_line() {
+    let hand = hand(&STARS_UNCALLED.replace("player1", "Tablesetter"));
+    assert_eq!(hand.table, "Aaltje III");
+    assert_eq!(hand.seats[0].player, "Tablesetter");
+    assert_eq!(hand.committed(), vec![5, 2, 5, 0]);
+    assert_eq!(hand.verify(), Ok(()));
+}
+
+#[test]
+fn test_side_pots() {
+    let hand = hand(STARS_SIDE_POT);
+    assert_eq!((hand.small_blind, hand.big_blind), (1500, 3000));
+    assert_eq!(hand.committed(), vec![40000, 100000, 100000]);
+    assert_eq!(hand.awards(), Ok(vec![120000, 120000, 0]));
+    assert_eq!(hand.verify(), Ok(()));
+
+    let showdown = hand.showdown().unwrap();
+    assert_eq!(showdown.len(), 3);
+    assert_eq!(showdown[0].1.category, HandRank::OnePair);
+    assert_eq!(showdown[0].1.cards[0].rank, Rank::Ace);
+}
+
+#[test]
+fn test_ggpoker_split_pot_with_fees() {
+    let hand = hand(GG_SPLIT_POT);
+    assert_eq!(hand.site, Site::GgPoker);
+    assert_eq!(hand.id, "RC1800000001");
+    assert_eq!(hand.seats[0].hole, hole("10C JD"));
+    assert_eq!(hand.rake, 1);
+    assert_eq!(hand.awards(), Ok(vec![7, 7, 0]));
+    assert_eq!(hand.verify(), Ok(()));
+}
+
+#[test]
+fn test_winamax() {
+    let hand = hand(WINAMAX);
+    assert_eq!(hand.site, Site::Winamax);
+    assert_eq!(hand.id, "18876587-492053-1695486636");
+    assert_eq!(hand.table, "Nice 05");
+    assert_eq!(hand.seats[0].player, "Big Blinder");
+    assert_eq!(hand.seats[1].stack, 199);
+    assert_eq!(hand.button, 1);
+    assert_eq!(hand.pot(), 24);
+    assert_eq!(hand.verify(), Ok(()));
+}
+
+#[test]
+fn test_file_of_hands() {
+    let file = [
+        STARS_SHOWDOWN,
+        STARS_UNCALLED,
+        STARS_SIDE_POT,
+        GG_SPLIT_POT,
+        WINAMAX,
+    ]
+    .join("\n\n\n");
+    let hands = parse_histories(&format!("\u{feff}{file}")).unwrap();
+    assert_eq!(hands.len(), 5);
+    assert_eq!(hands[4].site, Site::Winamax);
+    assert!(hands.iter().all(|hand| hand.verify().is_ok()));
+    assert_eq!(parse_histories(""), Ok(vec![]));
+}
+
+#[test]
+fn test_wrong_awards_are_caught() {
+    let history = hand(&STARS_SHOWDOWN.replace("player2 collected", "hero collected"));
+    assert_eq!(
+        history.verify(),
+        Err(AwardError::WrongAward {
+            player: "player2".to_string(),
+            expected: 152,
+            collected: 0
+        })
+    );
+
+    let history = hand_with_rake(STARS_SHOWDOWN, "$0.05");
+    assert_eq!(
+        history.verify(),
+        Err(AwardError::PotMismatch {
+            pot: 152,
+            collected: 145,
+            rake: 5
+        })
+    );
+
+    let history = hand(&STARS_SHOWDOWN.replace("hero: shows [Ah Kd] (a pair of Kings)\n", ""));
+    assert!(history.verify().is_ok());
+    let history = hand(&STARS_SIDE_POT.replace("carol: shows [Qd Qs] (a pair of Queens)\n", ""));
+    assert_eq!(
+        history.verify(),
+        Err(AwardError::HandNotShown("carol".to_string()))
+    );
+}
+
+fn hand_with_rake(text: &str, rake: &str) -> HandHistory {
+    hand(&text.replace("Rake $0.07", &format!("Rake {rake}")))
+}
+
+#[test]
+fn test_invalid_histories() {
+    assert!(matches!(
+        "Full Tilt Poker Game #1".parse::<HandHistory>(),
+        Err(HistoryError::UnknownFormat(_))
+    ));
+    assert!(matches!(
+        STARS_SHOWDOWN
+            .replace("Hold'em No Limit", "Omaha Pot Limit")
+            .parse::<HandHistory>(),
+        Err(HistoryError::UnsupportedGame(_))
+    ));
+    assert_eq!(
+        STARS_SHOWDOWN
+            .replace("calls $0.10", "calls $0.105")
+            .parse::<HandHistory>(),
+        Err(HistoryError::InvalidAmount("$0.105".to_string()))
+    );
+    assert!(matches!(
+        STARS_SHOWDOWN
+            .replace("[Ah Kd]", "[Ah Xd]")
+            .parse::<HandHistory>(),
+        Err(HistoryError::InvalidCard(_))
+    ));
+}
@@ -0,0 +1,202 @@
+#![cfg(feature = "std")]
+
+use poker::{parse_cards, Action, Card, Deck, Game, GameError, Rng, Street};
+
+// Build a deck that deals the given hole cards (two per seat, in seat order)
+// followed by the burn-and-board sequence for a five card board.
+fn stacked_deck(holes: &[&str], board: &str) -> Deck {
+    let mut order: Vec<Card> = holes
+        .iter()
+        .flat_map(|hole| parse_cards(hole).unwrap())
+        .collect();
+    let board = parse_cards(board).unwrap();
+    let burn = |order: &Vec<Card>| {
+        Card::all()
+            .find(|card| !order.contains(card) && !board.contains(card))
+            .unwrap()
+    };
+    for street in [&board[0..3], &board[3..4], &board[4..5]] {
+        order.push(burn(&order));
+        order.extend_from_slice(street);
+    }
+
+    let mut rest: Vec<Card> = Card::all().filter(|card| !order.contains(card)).collect();
+    rest.extend(order.into_iter().rev());
+    Deck::from(rest)
+}
+
+fn play(game: &mut Game, actions: &[Action]) {
+    for &action in actions {
+        game.apply(action).unwrap();
+    }
+}
+
+#[test]
+fn test_blinds_are_posted_heads_up() {
+    let deck = stacked_deck(&["AS AD", "KS KD"], "2C 7D 9H JC 3S");
+    let game = Game::new(&[100, 100], 0, 1, 2, deck).unwrap();
+    assert_eq!(game.seats()[0].stack, 99);
+    assert_eq!(game.seats()[1].stack, 98);
+    assert_eq!(game.to_act(), Some(0));
+    assert_eq!(game.pot(), 3);
+}
+
+#[test]
+fn test_blinds_are_posted_left_of_the_button() {
+    let deck = stacked_deck(&["AS AD", "KS KD", "QS QD"], "2C 7D 9H JC 3S");
+    let game = Game::new(&[100, 100, 100], 0, 1, 2, deck).unwrap();
+    assert_eq!(game.seats()[1].bet, 1);
+    assert_eq!(game.seats()[2].bet, 2);
+    assert_eq!(game.to_act(), Some(0));
+}
+
+#[test]
+fn test_small_blind_above_the_big_blind_is_rejected() {
+    let deck = stacked_deck(&["AS AD", "KS KD"], "2C 7D 9H JC 3S");
+    assert_eq!(
+        Game::new(&[100, 100], 0, 5, 2, deck).unwrap_err(),
+        GameError::BlindsOutOfOrder
+    );
+}
+
+#[test]
+fn test_fold_awards_the_pot() {
+    let deck = stacked_deck(&["AS AD", "KS KD"], "2C 7D 9H JC 3S");
+    let mut game = Game::new(&[100, 100], 0, 1, 2, deck).unwrap();
+    play(&mut game, &[Action::Fold]);
+    assert!(game.is_over());
+    assert_eq!(game.payouts(), Some(&[0, 3][..]));
+    assert_eq!(game.seats()[1].stack, 101);
+    assert!(game.showdown().is_empty());
+}
+
+#[test]
+fn test_hand_checked_down_to_showdown() {
+    let deck = stacked_deck(&["AS AD", "KS KD"], "2C 7D 9H JC 3S");
+    let mut game = Game::new(&[100, 100], 0, 1, 2, deck).unwrap();
+    play(&mut game, &[Action::Call, Action::Check]);
+    assert_eq!(game.street(), Street::Flop);
+    assert_eq!(game.to_act(), Some(1));
+    play(&mut game, &[Action::Check; 6]);
+
+    assert_eq!(game.street(), Street::Showdown);
+    assert_eq!(game.board(), &parse_cards("2C 7D 9H JC 3S").unwrap()[..]);
+    assert_eq!(game.showdown().len(), 2);
+    assert_eq!(game.payouts(), Some(&[4, 0][..]));
+}
+
+#[test]
+fn test_split_pot() {
+    let deck = stacked_deck(&["2S 3D", "2H 3C"], "10C JD QH KC AS");
+    let mut game = Game::new(&[100, 100], 0, 1, 2, deck).unwrap();
+    play(&mut game, &[Action::Call, Action::Check]);
+    play(&mut game, &[Action::Check; 6]);
+    assert_eq!(game.payouts(), Some(&[2, 2][..]));
+}
+
+#[test]
+fn test_illegal_actions_are_rejected() {
+    let deck = stacked_deck(&["AS AD", "KS KD"], "2C 7D 9H JC 3S");
+    let mut game = Game::new(&[100, 100], 0, 1, 2, deck).unwrap();
+    assert_eq!(game.apply(Action::Check), Err(GameError::CannotCheck));
+    assert_eq!(
+        game.apply(Action::Raise(3)),
+        Err(GameError::RaiseTooSmall { min: 4 })
+    );
+    assert_eq!(
+        game.apply(Action::Raise(101)),
+        Err(GameError::RaiseTooLarge { max: 100 })
+    );
+    play(&mut game, &[Action::Call]);
+    assert_eq!(game.apply(Action::Call), Err(GameError::NothingToCall));
+}
+
+#[test]
+fn test_raise_reopens_the_action() {
+    let deck = stacked_deck(&["AS AD", "KS KD"], "2C 7D 9H JC 3S");
+    let mut game = Game::new(&[100, 100], 0, 1, 2, deck).unwrap();
+    play(&mut game, &[Action::Raise(6), Action::Raise(20)]);
+    assert_eq!(game.to_act(), Some(0));
+    assert_eq!(game.min_raise_to(), 34);
+    play(&mut game, &[Action::Call]);
+    assert_eq!(game.street(), Street::Flop);
+    assert_eq!(game.pot(), 40);
+}
+
+#[test]
+fn test_short_all_in_does_not_reopen_the_action() {
+    let deck = stacked_deck(&["AS AD", "KS KD", "QS QD"], "2C 7D 9H JC 3S");
+    let mut game = Game::new(&[1000, 1000, 150], 0, 5, 10, deck).unwrap();
+    // The big blind's all in to 150 is less than a full raise over 100.
+    play(
+        &mut game,
+        &[Action::Raise(100), Action::Call, Action::Raise(150)],
+    );
+    assert_eq!(game.to_act(), Some(0));
+    assert_eq!(game.state(0).to_call, 50);
+    assert_eq!(game.max_raise_to(0), 150);
+    assert_eq!(
+        game.apply(Action::Raise(300)),
+        Err(GameError::RaisingClosed)
+    );
+
+    play(&mut game, &[Action::Call]);
+    assert_eq!(
+        game.apply(Action::Raise(400)),
+        Err(GameError::RaisingClosed)
+    );
+    play(&mut game, &[Action::Call]);
+    assert_eq!(game.street(), Street::Flop);
+    assert_eq!(game.pot(), 450);
+}
+
+#[test]
+fn test_all_in_runs_out_the_board_with_side_pots() {
+    // Seat 2 is all in for 20 with the best hand, seats 0 and 1 play for a
+    // side pot which seat 1 wins.
+    let deck = stacked_deck(&["QS QD", "KS KD", "AS AD"], "2C 7D 9H JC 3S");
+    let mut game = Game::new(&[100, 100, 20], 0, 1, 2, deck).unwrap();
+    play(&mut game, &[Action::Raise(50), Action::Call, Action::Call]);
+    assert_eq!(game.street(), Street::Flop);
+    play(&mut game, &[Action::Raise(50), Action::Call]);
+
+    assert!(game.is_over());
+    assert_eq!(game.board().len(), 5);
+    assert_eq!(game.payouts(), Some(&[0, 160, 60][..]));
+}
+
+#[test]
+fn test_uncalled_bet_is_returned() {
+    let deck = stacked_deck(&["AS AD", "KS KD"], "2C 7D 9H JC 3S");
+    let mut game = Game::new(&[100, 40], 0, 1, 2, deck).unwrap();
+    play(&mut game, &[Action::Raise(100), Action::Call]);
+    assert_eq!(game.payouts(), Some(&[140, 0][..]));
+}
+
+#[test]
+fn test_chips_are_conserved_in_random_games() {
+    let mut rng = Rng::new(2023);
+    for hand in 0..500 {
+        let players = 2 + rng.below(7);
+        let stacks: Vec<u32> = (0..players).map(|_| 1 + rng.below(300) as u32).collect();
+        let mut game = Game::new(&stacks, hand, 1, 2, Deck::shuffled(&mut rng)).unwrap();
+
+        while let Some(seat) = game.to_act() {
+            let action = match rng.below(4) {
+                0 => Action::Fold,
+                1 | 2 if game.to_call(seat) == 0 => Action::Check,
+                1 | 2 => Action::Call,
+                _ => {
+                    let min = game.min_raise_to().min(game.max_raise_to(seat));
+                    Action::Raise(min.max(game.current_bet() + 1))
+                }
+            };
+            if game.apply(action).is_err() {
+                game.apply(Action::Fold).unwrap();
+            }
+        }
+
+        let total: u32 = game.seats().iter().map(|seat| seat.stack).sum();
+        assert_eq!(total, stacks.iter().sum::<u32>());
+    }
+}
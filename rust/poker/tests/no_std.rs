@@ -0,0 +1,94 @@
+// Only what builds without std, so that `cargo test --no-default-features`
+// covers the core: cards are built from ranks and suits rather than parsed.
+
+use poker::{
+    evaluate, Accumulator, Card, Evaluator, Exhaustive, HandRank, Incremental, Rank, Rng, Suit,
+};
+
+fn hand(cards: &[(Rank, Suit)]) -> Vec<Card> {
+    cards
+        .iter()
+        .map(|&(rank, suit)| Card::new(rank, suit))
+        .collect()
+}
+
+#[test]
+fn test_categories() {
+    use Rank::*;
+    use Suit::*;
+
+    let cases = [
+        (
+            hand(&[
+                (Five, Hearts),
+                (Four, Hearts),
+                (Three, Hearts),
+                (Two, Hearts),
+                (Ace, Hearts),
+            ]),
+            HandRank::StraightFlush,
+        ),
+        (
+            hand(&[
+                (Nine, Clubs),
+                (Nine, Diamonds),
+                (Nine, Hearts),
+                (Nine, Spades),
+                (Two, Clubs),
+            ]),
+            HandRank::FourOfAKind,
+        ),
+        (
+            hand(&[
+                (Ace, Spades),
+                (Two, Diamonds),
+                (Three, Clubs),
+                (Four, Hearts),
+                (Five, Spades),
+            ]),
+            HandRank::Straight,
+        ),
+        (
+            hand(&[
+                (Ace, Spades),
+                (Ace, Diamonds),
+                (King, Clubs),
+                (Queen, Hearts),
+                (Nine, Spades),
+            ]),
+            HandRank::OnePair,
+        ),
+    ];
+    for (cards, category) in cases {
+        let evaluation = evaluate(&cards).unwrap();
+        assert_eq!(evaluation.category, category);
+
+        let strength = Some((category, evaluation.strength));
+        assert_eq!(Exhaustive.strength(&cards), strength);
+        assert_eq!(Incremental.strength(&cards), strength);
+        let accumulator: Accumulator = cards.iter().copied().collect();
+        assert_eq!(accumulator.strength(), strength);
+    }
+}
+
+#[test]
+fn test_accumulator_agrees_with_evaluate() {
+    let mut deck = [Card::new(Rank::Two, Suit::Spades); 52];
+    for (slot, card) in deck.iter_mut().zip(Card::all()) {
+        *slot = card;
+    }
+
+    let mut rng = Rng::new(47);
+    for _ in 0..500 {
+        rng.shuffle(&mut deck);
+        let mut accumulator = Accumulator::new();
+        for (dealt, &card) in deck[..7].iter().enumerate() {
+            assert!(accumulator.add(card));
+            let expected = evaluate(&deck[..=dealt]).map(|evaluation| evaluation.strength);
+            assert_eq!(
+                accumulator.strength().map(|(_, strength)| strength),
+                expected
+            );
+        }
+    }
+}
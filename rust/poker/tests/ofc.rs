@@ -0,0 +1,125 @@
+#![cfg(feature = "std")]
+
+use poker::{
+    evaluate, evaluate_top, parse_cards, score_table, HandRank, OfcError, OfcHand, OfcScore,
+};
+
+fn hand(rows: &str) -> OfcHand {
+    rows.parse().unwrap()
+}
+
+fn top_strength(cards: &str) -> u32 {
+    let cards = parse_cards(cards).unwrap();
+    evaluate_top([cards[0], cards[1], cards[2]]).strength
+}
+
+fn strength(cards: &str) -> u32 {
+    evaluate(&parse_cards(cards).unwrap()).unwrap().strength
+}
+
+// Top pair of queens, king high flush in the middle, fours full on the bottom.
+const STRONG: &str = "QS QH 2D / 3H 7H 9H JH KH / 4S 4C 4D 8S 8C";
+// Ace high on top, a pair of sixes in the middle and nines on the bottom.
+const WEAK: &str = "AS KD 5C / 6S 6D 10C JC 2C / 9S 9D 9C QC 3S";
+// Aces on top over fives in the middle.
+const FOULED: &str = "AD AC 3D / 5D 5S 7C 8D 10D / 2S 2H 6C 7S QD";
+
+#[test]
+fn test_top_row_categories() {
+    let cards = parse_cards("7S 7H 7D").unwrap();
+    let top = evaluate_top([cards[0], cards[1], cards[2]]);
+    assert_eq!(top.category, HandRank::ThreeOfAKind);
+
+    let cards = parse_cards("2S KH 2D").unwrap();
+    let top = evaluate_top([cards[0], cards[1], cards[2]]);
+    assert_eq!(top.category, HandRank::OnePair);
+    assert_eq!(top.cards.map(|card| card.to_string()), ["2S", "2D", "KH"]);
+}
+
+#[test]
+fn test_top_row_compares_with_five_card_rows() {
+    assert!(top_strength("KS KH AD") < strength("KC KD AS QH JH"));
+    assert!(top_strength("KS KH AD") > strength("KC KD QS JH 10H"));
+    assert!(top_strength("QS QH QD") > strength("AS AH KD QC JS"));
+    assert!(top_strength("QS QH QD") < strength("2S 3H 4D 5C 6S"));
+    assert!(top_strength("AS KH QD") < strength("2S 2H 3D 4C 5S"));
+}
+
+#[test]
+fn test_fouling() {
+    assert!(!hand(STRONG).is_fouled());
+    assert!(!hand(WEAK).is_fouled());
+    assert!(hand(FOULED).is_fouled());
+    assert!(hand("2S 3H 4D / AS AH KD QC JS / KS KH 5D 4C 9S").is_fouled());
+    assert!(!hand("KS KH AD / KC KD AS QH JH / 2C 3C 4C 5C 7C").is_fouled());
+}
+
+#[test]
+fn test_royalties() {
+    assert_eq!(hand(STRONG).royalties(), 7 + 8 + 6);
+    assert_eq!(hand(WEAK).royalties(), 0);
+    assert_eq!(hand(FOULED).royalties(), 0);
+    assert_eq!(
+        hand("2D 2C 2H / 10S JS QS KS AS / 10H JH QH KH AH").royalties(),
+        10 + 50 + 25
+    );
+    assert_eq!(
+        hand("5S 5H 2D / 6D 6C 6H 3S 4S / 7D 8D 9D 10D JD").royalties(),
+        2 + 15
+    );
+}
+
+#[test]
+fn test_scoop() {
+    let score = hand(STRONG).score(&hand(WEAK));
+    assert_eq!(
+        score,
+        OfcScore {
+            rows: 3,
+            scoop: 3,
+            royalties: 21
+        }
+    );
+    assert_eq!(score.total(), 27);
+    assert_eq!(hand(WEAK).score(&hand(STRONG)).total(), -27);
+}
+
+#[test]
+fn test_split_rows() {
+    let a = hand("AS KD 5C / 6S 6D 10C JC 2C / 9S 9D 9C QC 3S");
+    let b = hand("2S 3H 4C / 7S 7D 10D JD 2D / 8S 8D 8C QD 3C");
+    let score = a.score(&b);
+    assert_eq!(score.rows, 1);
+    assert_eq!(score.scoop, 0);
+    assert_eq!(score.total(), 1);
+}
+
+#[test]
+fn test_fouled_hand_is_scooped() {
+    assert_eq!(hand(WEAK).score(&hand(FOULED)).total(), 6);
+    assert_eq!(hand(STRONG).score(&hand(FOULED)).total(), 27);
+    assert_eq!(hand(FOULED).score(&hand(FOULED)), OfcScore::default());
+}
+
+#[test]
+fn test_score_table_is_zero_sum() {
+    let table = score_table(&[hand(STRONG), hand(WEAK), hand(FOULED)]);
+    assert_eq!(table, vec![54, -21, -33]);
+    assert_eq!(table.iter().sum::<i32>(), 0);
+}
+
+#[test]
+fn test_invalid_hands() {
+    assert_eq!(
+        "QS QH / 3H 7H 9H JH KH / 4S 4C 4D 8S 8C".parse::<OfcHand>(),
+        Err(OfcError::WrongRowSizes(vec![2, 5, 5]))
+    );
+    assert_eq!(
+        "QS QH 3H / 3H 7H 9H JH KH / 4S 4C 4D 8S 8C".parse::<OfcHand>(),
+        Err(OfcError::DuplicateCard("3H".parse().unwrap()))
+    );
+    assert!(matches!(
+        "QS QH 1H / 3H 7H 9H JH KH / 4S 4C 4D 8S 8C".parse::<OfcHand>(),
+        Err(OfcError::InvalidCard(_))
+    ));
+}
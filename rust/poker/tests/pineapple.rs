@@ -0,0 +1,110 @@
+#![cfg(feature = "std")]
+
+use poker::{
+    discard_equities, parse_cards, Card, Deck, EquityError, PineappleDeal, PineappleError,
+    PineappleVariant, Street,
+};
+
+fn cards(cards: &str) -> Vec<Card> {
+    parse_cards(cards).unwrap()
+}
+
+#[test]
+fn test_classic_discards_before_the_flop() {
+    let mut deal = PineappleDeal::new(PineappleVariant::Classic, 3, Deck::new()).unwrap();
+    assert_eq!(deal.street(), Street::Preflop);
+    assert!((0..3).all(|seat| deal.hand(seat).len() == 3));
+    assert_eq!(
+        deal.deal_street(),
+        Err(PineappleError::WaitingForDiscards(vec![0, 1, 2]))
+    );
+
+    for seat in 0..3 {
+        let card = deal.hand(seat)[2];
+        deal.discard(seat, card).unwrap();
+        assert_eq!(deal.discarded(seat), Some(card));
+        assert_eq!(deal.hand(seat).len(), 2);
+    }
+    assert_eq!(deal.deal_street().unwrap().len(), 3);
+    assert_eq!(deal.deal_street().unwrap().len(), 4);
+    assert_eq!(deal.deal_street().unwrap().len(), 5);
+    assert_eq!(deal.street(), Street::River);
+    assert_eq!(deal.deal_street(), Err(PineappleError::HandOver));
+
+    let showdown = deal.showdown().unwrap();
+    assert_eq!(showdown.hands.len(), 3);
+    assert!(!showdown.winners.is_empty());
+}
+
+#[test]
+fn test_crazy_discards_after_the_flop() {
+    let mut deal = PineappleDeal::new(PineappleVariant::Crazy, 2, Deck::new()).unwrap();
+    let card = deal.hand(0)[0];
+    assert_eq!(deal.discard(0, card), Err(PineappleError::TooEarly));
+    assert_eq!(deal.deal_street().unwrap().len(), 3);
+
+    deal.discard(0, card).unwrap();
+    assert_eq!(
+        deal.discard(0, deal.hand(0)[0]),
+        Err(PineappleError::AlreadyDiscarded(0))
+    );
+    assert_eq!(
+        deal.discard(1, card),
+        Err(PineappleError::NotInHand { seat: 1, card })
+    );
+    assert_eq!(
+        deal.deal_street(),
+        Err(PineappleError::WaitingForDiscards(vec![1]))
+    );
+
+    let card = deal.hand(1)[1];
+    deal.discard(1, card).unwrap();
+    assert_eq!(deal.deal_street().unwrap().len(), 4);
+}
+
+#[test]
+fn test_deal_needs_players_and_cards() {
+    assert!(matches!(
+        PineappleDeal::new(PineappleVariant::Classic, 1, Deck::new()),
+        Err(PineappleError::NotEnoughPlayers)
+    ));
+    assert!(matches!(
+        PineappleDeal::new(PineappleVariant::Classic, 15, Deck::new()),
+        Err(PineappleError::NotEnoughCards)
+    ));
+}
+
+#[test]
+fn test_discard_equities_on_the_flop() {
+    // Keeping the straight flush draw beats keeping a pair of deuces.
+    let hand = cards("JH 10H 2C");
+    let options = discard_equities(
+        [hand[0], hand[1], hand[2]],
+        &[cards("AS AD")],
+        &cards("9H 8H 2D"),
+        &cards("KC"),
+    )
+    .unwrap();
+
+    assert_eq!(options.len(), 3);
+    assert_eq!(options[0].discard, hand[2]);
+    assert_eq!(options[0].kept, [hand[0], hand[1]]);
+    assert!(options[0].equity.equity > 0.5);
+    assert!(options
+        .windows(2)
+        .all(|pair| pair[0].equity.equity >= pair[1].equity.equity));
+}
+
+#[test]
+fn test_discard_equities_reject_duplicates() {
+    let hand = cards("JH 10H 2C");
+    assert_eq!(
+        discard_equities(
+            [hand[0], hand[1], hand[2]],
+            &[cards("AS 2C")],
+            &cards("9H 8H 2D"),
+            &[],
+        ),
+        Err(EquityError::DuplicateCard(hand[2]))
+    );
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "std")]
+
 use poker::winning_hands;
 use std::collections::HashSet;
 
@@ -0,0 +1,99 @@
+#![cfg(feature = "std")]
+
+use poker::{preflop_equity, HeadsUpEquities, PreflopChart, Rank, Rng, StartingHand};
+
+fn hand(name: &str) -> StartingHand {
+    StartingHand::all()
+        .into_iter()
+        .find(|hand| hand.to_string() == name)
+        .unwrap()
+}
+
+#[test]
+fn test_there_are_169_starting_hands_covering_every_deal() {
+    let hands = StartingHand::all();
+    assert_eq!(hands.len(), 169);
+    assert_eq!(hands.iter().map(StartingHand::combos).sum::<usize>(), 1326);
+    assert_eq!(hands.iter().filter(|hand| hand.is_pair()).count(), 13);
+    assert_eq!(hands.iter().filter(|hand| hand.suited).count(), 78);
+}
+
+#[test]
+fn test_chart_order_and_names() {
+    let names: Vec<String> = StartingHand::all()[..14]
+        .iter()
+        .map(|h| h.to_string())
+        .collect();
+    assert_eq!(names[0], "AA");
+    assert_eq!(names[1], "AKs");
+    assert_eq!(names[4], "ATs");
+    assert_eq!(names[12], "A2s");
+    assert_eq!(names[13], "AKo");
+    assert_eq!(hand("TT").high, Rank::Ten);
+}
+
+#[test]
+fn test_concrete_cards_match_the_hand() {
+    for hand in StartingHand::all() {
+        let [a, b] = hand.cards();
+        assert_ne!(a, b);
+        assert_eq!(a.rank, hand.high);
+        assert_eq!(b.rank, hand.low);
+        assert_eq!(a.suit == b.suit, hand.suited);
+    }
+}
+
+#[test]
+fn test_aces_against_one_random_hand() {
+    let equity = preflop_equity(hand("AA"), 1, 4000, &mut Rng::new(1)).unwrap();
+    assert!((0.83..0.88).contains(&equity), "{equity}");
+}
+
+#[test]
+fn test_equity_falls_with_more_opponents() {
+    let mut rng = Rng::new(1);
+    let one = preflop_equity(hand("KK"), 1, 1000, &mut rng).unwrap();
+    let five = preflop_equity(hand("KK"), 5, 1000, &mut rng).unwrap();
+    assert!(five < one);
+}
+
+#[test]
+fn test_opponents_are_bounded() {
+    let mut rng = Rng::new(1);
+    assert_eq!(preflop_equity(hand("AA"), 0, 10, &mut rng), None);
+    assert!(preflop_equity(hand("AA"), 22, 10, &mut rng).is_some());
+    assert_eq!(preflop_equity(hand("AA"), 23, 10, &mut rng), None);
+    assert_eq!(PreflopChart::generate(0, 10, 1), None);
+    assert_eq!(PreflopChart::generate(10, 10, 1), None);
+}
+
+#[test]
+fn test_trials_are_required() {
+    assert_eq!(preflop_equity(hand("AA"), 1, 0, &mut Rng::new(1)), None);
+    assert_eq!(PreflopChart::generate(1, 0, 1), None);
+    assert_eq!(HeadsUpEquities::estimate(0, 1), None);
+}
+
+#[test]
+fn test_chart_is_reproducible_and_exports() {
+    let chart = PreflopChart::generate(1, 100, 7).unwrap();
+    assert_eq!(Some(chart.clone()), PreflopChart::generate(1, 100, 7));
+
+    let aces = chart.equity(hand("AA"), 1).unwrap();
+    let trash = chart.equity(hand("72o"), 1).unwrap();
+    assert!(aces > trash);
+    assert_eq!(chart.equity(hand("AA"), 2), None);
+
+    let csv = chart.to_csv();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines.len(), 170);
+    assert_eq!(lines[0], "hand,combos,vs1");
+    assert!(lines[1].starts_with("AA,6,0."));
+
+    let grid = chart.ascii_grid(1);
+    assert_eq!(grid.lines().count(), 14);
+    assert!(grid.lines().next().unwrap().trim().starts_with("A    K"));
+    assert!(StartingHand::all()
+        .iter()
+        .any(|h| h.high == Rank::Ten && h.to_string() == "TT"));
+}
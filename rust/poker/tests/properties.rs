@@ -0,0 +1,148 @@
+#![cfg(feature = "std")]
+
+use poker::{evaluate, winning_hands, Card, HandRank, Rank, Suit};
+use proptest::prelude::*;
+use proptest::sample::subsequence;
+
+// A deliberately simple evaluator to check the real one against: the
+// category, then the ranks that decide ties within it, most important first.
+fn reference(cards: &[Card]) -> (HandRank, Vec<Rank>) {
+    let mut counts = [0; 13];
+    for card in cards {
+        counts[card.rank as usize] += 1;
+    }
+    let mut groups: Vec<(usize, Rank)> = Rank::ALL
+        .iter()
+        .filter(|&&rank| counts[rank as usize] > 0)
+        .map(|&rank| (counts[rank as usize], rank))
+        .collect();
+    groups.sort_by(|a, b| b.cmp(a));
+    let ranks: Vec<Rank> = groups.iter().map(|&(_, rank)| rank).collect();
+    let shape: Vec<usize> = groups.iter().map(|&(n, _)| n).collect();
+
+    let flush = cards.iter().all(|card| card.suit == cards[0].suit);
+    let straight_high = match ranks[..] {
+        [Rank::Ace, Rank::Five, Rank::Four, Rank::Three, Rank::Two] => Some(Rank::Five),
+        [high, .., low] if shape.len() == 5 && high as usize - low as usize == 4 => Some(high),
+        _ => None,
+    };
+
+    match (straight_high, flush, &shape[..]) {
+        (Some(high), true, _) => (HandRank::StraightFlush, vec![high]),
+        (_, _, [4, 1]) => (HandRank::FourOfAKind, ranks),
+        (_, _, [3, 2]) => (HandRank::FullHouse, ranks),
+        (_, true, _) => (HandRank::Flush, ranks),
+        (Some(high), _, _) => (HandRank::Straight, vec![high]),
+        (_, _, [3, 1, 1]) => (HandRank::ThreeOfAKind, ranks),
+        (_, _, [2, 2, 1]) => (HandRank::TwoPair, ranks),
+        (_, _, [2, 1, 1, 1]) => (HandRank::OnePair, ranks),
+        _ => (HandRank::HighCard, ranks),
+    }
+}
+
+fn hand_string(cards: &[Card]) -> String {
+    let cards: Vec<String> = cards.iter().map(Card::to_string).collect();
+    cards.join(" ")
+}
+
+fn cards(range: std::ops::RangeInclusive<usize>) -> impl Strategy<Value = Vec<Card>> {
+    subsequence(Card::all().collect::<Vec<_>>(), range).prop_shuffle()
+}
+
+proptest! {
+    #[test]
+    fn test_order_of_cards_does_not_matter(
+        (hand, shuffled) in cards(5..=7).prop_flat_map(|hand| (Just(hand.clone()), Just(hand).prop_shuffle()))
+    ) {
+        prop_assert_eq!(
+            evaluate(&hand).map(|e| e.strength),
+            evaluate(&shuffled).map(|e| e.strength)
+        );
+    }
+
+    #[test]
+    fn test_relabelling_suits_does_not_matter(
+        hand in cards(5..=7),
+        suits in Just(Suit::ALL.to_vec()).prop_shuffle(),
+    ) {
+        let relabelled: Vec<Card> = hand
+            .iter()
+            .map(|card| Card::new(card.rank, suits[card.suit.index() as usize]))
+            .collect();
+        prop_assert_eq!(
+            evaluate(&hand).map(|e| e.strength),
+            evaluate(&relabelled).map(|e| e.strength)
+        );
+    }
+
+    #[test]
+    fn test_best_hand_beats_every_five(hand in cards(6..=7)) {
+        let best = evaluate(&hand).unwrap();
+        for skip in 0..hand.len() {
+            let mut rest = hand.clone();
+            rest.remove(skip);
+            prop_assert!(evaluate(&rest).unwrap() <= best);
+        }
+    }
+
+    #[test]
+    fn test_winning_hands_agrees_with_reference(a in cards(5..=5), b in cards(5..=5)) {
+        let (a_string, b_string) = (hand_string(&a), hand_string(&b));
+        let expected = match reference(&a).cmp(&reference(&b)) {
+            std::cmp::Ordering::Greater => vec![a_string.as_str()],
+            std::cmp::Ordering::Less => vec![b_string.as_str()],
+            std::cmp::Ordering::Equal => vec![a_string.as_str(), b_string.as_str()],
+        };
+        prop_assert_eq!(winning_hands(&[&a_string, &b_string]), expected);
+    }
+
+    #[test]
+    fn test_winning_hands_never_panics(hands in prop::collection::vec(".*", 0..4)) {
+        let hands: Vec<&str> = hands.iter().map(String::as_str).collect();
+        winning_hands(&hands);
+    }
+
+    #[test]
+    fn test_winning_hands_never_panics_on_card_soup(
+        hands in prop::collection::vec("([2-9JQKA0]|10)[SHDC]?( ([2-9JQKA]|10)[SHDC]){0,12}", 0..4)
+    ) {
+        let hands: Vec<&str> = hands.iter().map(String::as_str).collect();
+        winning_hands(&hands);
+    }
+}
+
+#[test]
+fn test_agrees_with_reference_on_every_hand() {
+    let deck: Vec<Card> = Card::all().collect();
+    let mut hands = Vec::with_capacity(2_598_960);
+    for a in 0..48 {
+        for b in a + 1..49 {
+            for c in b + 1..50 {
+                for d in c + 1..51 {
+                    for e in d + 1..52 {
+                        let hand = [deck[a], deck[b], deck[c], deck[d], deck[e]];
+                        let evaluation = evaluate(&hand).unwrap();
+                        let (category, ranks) = reference(&hand);
+                        assert_eq!(evaluation.category, category, "{}", hand_string(&hand));
+                        // Packed so the hands sort without allocating.
+                        let key = (0..5).fold(category as u32, |key, i| {
+                            key << 4 | ranks.get(i).map_or(0, |&rank| rank as u32 + 1)
+                        });
+                        hands.push((key, evaluation.strength));
+                    }
+                }
+            }
+        }
+    }
+    assert_eq!(hands.len(), 2_598_960);
+
+    // Sorted by the reference, strengths must rise exactly when it does.
+    hands.sort_unstable();
+    for pair in hands.windows(2) {
+        let [(a, a_strength), (b, b_strength)] = pair else {
+            unreachable!()
+        };
+        assert_eq!(a == b, a_strength == b_strength, "{a:#x} and {b:#x}");
+        assert!(a_strength <= b_strength, "{a:#x} and {b:#x}");
+    }
+}
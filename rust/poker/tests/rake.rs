@@ -0,0 +1,116 @@
+#![cfg(feature = "std")]
+
+use poker::{
+    hole_cards_played, parse_cards, BadBeatRule, Card, HandRank, RakeReason, RakeRule, Rank,
+};
+
+fn hole(cards: &str) -> [Card; 2] {
+    let cards = parse_cards(cards).unwrap();
+    [cards[0], cards[1]]
+}
+
+fn board(cards: &str) -> Vec<Card> {
+    parse_cards(cards).unwrap()
+}
+
+#[test]
+fn test_rake() {
+    let rule = RakeRule::default();
+
+    let rake = rule.rake(1000, true);
+    assert_eq!((rake.taken, rake.reason), (50, RakeReason::Percentage));
+    assert_eq!(rake.to_string(), "50 taken from 1000");
+
+    let rake = rule.rake(1019, true);
+    assert_eq!(rake.taken, 50);
+
+    let rake = rule.rake(10000, true);
+    assert_eq!((rake.uncapped, rake.taken), (500, 300));
+    assert_eq!(rake.reason, RakeReason::Capped);
+    assert_eq!(rake.to_string(), "300 taken from 10000, capped from 500");
+
+    let rake = rule.rake(10000, false);
+    assert_eq!((rake.taken, rake.reason), (0, RakeReason::NoFlop));
+
+    let rule = RakeRule {
+        no_flop_no_drop: false,
+        ..rule
+    };
+    assert_eq!(rule.rake(1000, false).taken, 50);
+}
+
+#[test]
+fn test_hole_cards_played() {
+    // Both jacks make the quads.
+    assert_eq!(
+        hole_cards_played(&hole("JS JH"), &board("JD JC 2S 7H 9C")),
+        2
+    );
+    // Only one jack is needed when the board has three.
+    assert_eq!(
+        hole_cards_played(&hole("JS 3H"), &board("JD JC JH 7H 9C")),
+        1
+    );
+    // The board plays.
+    assert_eq!(
+        hole_cards_played(&hole("2S 3H"), &board("AS KS QS JS 10S")),
+        0
+    );
+    // The ace in the hand can be the kicker, but the ace on the board does
+    // just as well.
+    assert_eq!(
+        hole_cards_played(&hole("AH 2C"), &board("AD 7S 7H 7D 7C")),
+        0
+    );
+}
+
+#[test]
+fn test_bad_beat() {
+    let rule = BadBeatRule::default();
+    let board = board("JD JC 5S 6S 7S");
+    let holes = [hole("JS JH"), hole("8S 9S"), hole("AD KD")];
+
+    let beat = rule.check(&board, &holes).unwrap().unwrap();
+    assert_eq!((beat.loser, beat.winner), (0, 1));
+    assert_eq!(beat.losing_hand.category, HandRank::FourOfAKind);
+    assert_eq!(beat.winning_hand.category, HandRank::StraightFlush);
+    assert_eq!((beat.loser_hole_cards, beat.winner_hole_cards), (2, 2));
+    assert_eq!(
+        beat.to_string(),
+        format!(
+            "hand 0 lost with {} (2 hole cards playing) to hand 1 with {} (2 hole cards playing)",
+            beat.losing_hand.description, beat.winning_hand.description
+        )
+    );
+
+    // Quad tens don't qualify.
+    let board_tens = self::board("10D 10C 5S 6S 7S");
+    let holes_tens = [hole("10S 10H"), hole("8S 9S")];
+    assert_eq!(rule.check(&board_tens, &holes_tens), Ok(None));
+    let quad_tens = BadBeatRule {
+        rank: Rank::Ten,
+        ..rule
+    };
+    assert!(quad_tens.check(&board_tens, &holes_tens).unwrap().is_some());
+
+    // Nobody lost.
+    assert_eq!(rule.check(&board, &holes[1..2]), Ok(None));
+}
+
+#[test]
+fn test_bad_beat_needs_hole_cards_to_play() {
+    let rule = BadBeatRule::default();
+    // Quads on the board: at most a kicker comes from the loser's hand.
+    let board = board("QD QC QS QH 2S");
+    let holes = [hole("3C 4C"), hole("AS 5D")];
+    assert_eq!(rule.check(&board, &holes), Ok(None));
+
+    let any_quads = BadBeatRule {
+        loser_hole_cards: 0,
+        winner_hole_cards: 0,
+        ..rule
+    };
+    let beat = any_quads.check(&board, &holes).unwrap().unwrap();
+    assert_eq!(beat.loser_hole_cards, 1);
+    assert_eq!(beat.winner_hole_cards, 1);
+}
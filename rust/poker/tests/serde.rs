@@ -0,0 +1,132 @@
+#![cfg(feature = "serde")]
+
+use poker::{
+    evaluate, parse_cards, showdown, Card, Evaluation, HandHistory, HandRank, Rank, Showdown, Suit,
+};
+use serde_json::json;
+
+#[test]
+fn test_card_serialises_as_its_string_form() {
+    let card = Card::new(Rank::Ten, Suit::Hearts);
+    assert_eq!(serde_json::to_value(card).unwrap(), json!("10H"));
+}
+
+#[test]
+fn test_hand_serialises_as_a_list_of_cards() {
+    let hand = parse_cards("4S 5S 7H 8D JC").unwrap();
+    assert_eq!(
+        serde_json::to_value(&hand).unwrap(),
+        json!(["4S", "5S", "7H", "8D", "JC"])
+    );
+}
+
+#[test]
+fn test_hand_round_trips() {
+    let hand = parse_cards("2S 10H QD KC AS").unwrap();
+    let json = serde_json::to_string(&hand).unwrap();
+    assert_eq!(serde_json::from_str::<Vec<Card>>(&json).unwrap(), hand);
+}
+
+#[test]
+fn test_invalid_card_fails_to_deserialise() {
+    assert!(serde_json::from_str::<Card>("\"1H\"").is_err());
+    assert!(serde_json::from_str::<Card>("\"AX\"").is_err());
+}
+
+#[test]
+fn test_hand_rank_uses_snake_case() {
+    assert_eq!(
+        serde_json::to_value(HandRank::StraightFlush).unwrap(),
+        json!("straight_flush")
+    );
+    assert_eq!(
+        serde_json::from_value::<HandRank>(json!("two_pair")).unwrap(),
+        HandRank::TwoPair
+    );
+}
+
+#[test]
+fn test_evaluation_schema() {
+    let evaluation = evaluate(&parse_cards("4S 4H 7H 7D JC").unwrap()).unwrap();
+    assert_eq!(
+        serde_json::to_value(&evaluation).unwrap(),
+        json!({
+            "category": "two_pair",
+            "strength": evaluation.strength,
+            "cards": ["7H", "7D", "4S", "4H", "JC"],
+        })
+    );
+}
+
+#[test]
+fn test_evaluation_round_trips() {
+    let evaluation = evaluate(&parse_cards("AS 2S 3S 4S 5S").unwrap()).unwrap();
+    let json = serde_json::to_string(&evaluation).unwrap();
+    assert_eq!(
+        serde_json::from_str::<Evaluation>(&json).unwrap(),
+        evaluation
+    );
+}
+
+#[test]
+fn test_showdown_schema() {
+    let hands = [
+        parse_cards("AS AH KD 7C 2S").unwrap(),
+        parse_cards("3S 4H 5D 8C JS").unwrap(),
+    ];
+    let result = showdown(&hands).unwrap();
+    let value = serde_json::to_value(&result).unwrap();
+    assert_eq!(value["winners"], json!([0]));
+    assert_eq!(
+        value["hands"][0],
+        json!({
+            "category": "one_pair",
+            "strength": result.hands[0].strength,
+            "cards": ["AS", "AH", "KD", "7C", "2S"],
+            "kickers": ["KD", "7C", "2S"],
+            "description": "pair of aces",
+        })
+    );
+    assert_eq!(serde_json::from_value::<Showdown>(value).unwrap(), result);
+}
+
+#[test]
+fn test_hand_history_schema() {
+    let history: HandHistory = "\
+PokerStars Hand #1:  Hold'em No Limit ($0.02/$0.05 USD) - 2024/01/05 12:35:40 ET
+Table 'Aaltje III' 6-max Seat #1 is the button
+Seat 1: player1 ($4.98 in chips)
+Seat 2: hero ($4.57 in chips)
+player1: posts small blind $0.02
+hero: posts big blind $0.05
+*** HOLE CARDS ***
+Dealt to hero [7c 2d]
+player1: folds
+hero collected $0.04 from pot
+*** SUMMARY ***
+Total pot $0.04 | Rake $0
+"
+    .parse()
+    .unwrap();
+    let value = serde_json::to_value(&history).unwrap();
+    assert_eq!(value["site"], json!("poker_stars"));
+    assert_eq!(
+        value["seats"][1],
+        json!({
+            "number": 2,
+            "player": "hero",
+            "stack": 457,
+            "hole": ["7C", "2D"],
+            "collected": 4,
+        })
+    );
+    assert_eq!(
+        value["actions"][2],
+        json!({"street": "preflop", "seat": 0, "kind": "fold"})
+    );
+    assert_eq!(value["actions"][1]["kind"], json!({"blind": 5}));
+    assert_eq!(
+        serde_json::from_value::<HandHistory>(value).unwrap(),
+        history
+    );
+}
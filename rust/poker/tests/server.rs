@@ -0,0 +1,186 @@
+#![cfg(feature = "std")]
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpStream;
+use std::thread;
+use std::time::Duration;
+
+use poker::{parse_cards, Action, Client, Message, Server, ServerConfig, Turn};
+
+fn start(config: ServerConfig) -> (String, thread::JoinHandle<Vec<u32>>) {
+    let server = Server::bind("127.0.0.1:0", config).unwrap();
+    let addr = server.local_addr().unwrap().to_string();
+    (addr, thread::spawn(move || server.run().unwrap()))
+}
+
+fn calling_station(_: &Client, turn: &Turn) -> Action {
+    match turn.to_call {
+        0 => Action::Check,
+        _ => Action::Call,
+    }
+}
+
+#[test]
+fn test_message_round_trips() {
+    let messages = [
+        Message::Join("Ada Lovelace".to_string()),
+        Message::Play(Action::Raise(40)),
+        Message::Play(Action::Fold),
+        Message::Seat(3),
+        Message::Hand {
+            number: 7,
+            button: 1,
+            stacks: vec![100, 0, 250],
+        },
+        Message::Hole(["AS".parse().unwrap(), "10D".parse().unwrap()]),
+        Message::Board(parse_cards("2C 7D 9H").unwrap()),
+        Message::Turn(Turn {
+            to_call: 10,
+            min_raise_to: 30,
+            max_raise_to: 500,
+            pot: 45,
+        }),
+        Message::Acted {
+            seat: 2,
+            action: Action::Call,
+        },
+        Message::Show {
+            seat: 0,
+            hole: ["AS".parse().unwrap(), "AD".parse().unwrap()],
+            description: "pair of aces".to_string(),
+        },
+        Message::Win {
+            seat: 0,
+            amount: 90,
+        },
+        Message::End,
+        Message::GameOver(vec![1000, 0]),
+        Message::Error("raise must be to at least 20".to_string()),
+    ];
+
+    for message in messages {
+        assert_eq!(message.to_string().parse(), Ok(message));
+    }
+}
+
+#[test]
+fn test_garbage_is_not_a_message() {
+    assert!("RAISE lots".parse::<Message>().is_err());
+    assert!("HOLE AS".parse::<Message>().is_err());
+    assert!("".parse::<Message>().is_err());
+}
+
+#[test]
+fn test_scripted_clients_play_a_game_over_loopback() {
+    let (addr, server) = start(ServerConfig {
+        seats: 3,
+        hands: 10,
+        seed: 42,
+        ..ServerConfig::default()
+    });
+
+    let clients: Vec<_> = (0..3)
+        .map(|n| {
+            let client = Client::connect(&addr, &format!("bot {n}")).unwrap();
+            thread::spawn(move || {
+                let seat = client.seat();
+                let stacks = client
+                    .play(|client, turn| {
+                        assert!(client.hole().is_some());
+                        match seat {
+                            0 => Action::Raise(turn.min_raise_to),
+                            _ => calling_station(client, turn),
+                        }
+                    })
+                    .unwrap();
+                (seat, stacks)
+            })
+        })
+        .collect();
+
+    let stacks = server.join().unwrap();
+    assert_eq!(stacks.iter().sum::<u32>(), 3000);
+    for client in clients {
+        let (_, client_stacks) = client.join().unwrap();
+        assert_eq!(client_stacks, stacks);
+    }
+}
+
+#[test]
+fn test_only_own_hole_cards_are_sent() {
+    let (addr, server) = start(ServerConfig {
+        hands: 1,
+        ..ServerConfig::default()
+    });
+
+    let mut folder = Client::connect(&addr, "folder").unwrap();
+    let caller = Client::connect(&addr, "caller").unwrap();
+    let caller = thread::spawn(move || caller.play(calling_station).unwrap());
+
+    let mut holes = 0;
+    while let Some(message) = folder.recv().unwrap() {
+        match message {
+            Message::Hole(_) => holes += 1,
+            Message::Turn(_) => folder.send(&Message::Play(Action::Fold)).unwrap(),
+            Message::GameOver(_) => break,
+            _ => {}
+        }
+    }
+
+    assert_eq!(holes, 1);
+    caller.join().unwrap();
+    server.join().unwrap();
+}
+
+#[test]
+fn test_illegal_actions_get_an_error_and_another_turn() {
+    let (addr, server) = start(ServerConfig {
+        hands: 1,
+        ..ServerConfig::default()
+    });
+
+    let stream = TcpStream::connect(&addr).unwrap();
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    let mut writer = stream;
+    let mut line = String::new();
+    let mut recv = |line: &mut String| {
+        line.clear();
+        reader.read_line(line).unwrap();
+        line.trim_end().to_string()
+    };
+
+    writeln!(writer, "JOIN raw").unwrap();
+    assert_eq!(recv(&mut line), "SEAT 0");
+    let caller = Client::connect(&addr, "caller").unwrap();
+    let caller = thread::spawn(move || caller.play(calling_station).unwrap());
+
+    // Seat 0 has the button and acts first heads up.
+    while !recv(&mut line).starts_with("TURN") {}
+    writeln!(writer, "CHECK").unwrap();
+    assert_eq!(recv(&mut line), "ERROR cannot check facing a bet");
+    assert!(recv(&mut line).starts_with("TURN"));
+    writeln!(writer, "DANCE").unwrap();
+    assert!(recv(&mut line).starts_with("ERROR unrecognised message"));
+    assert!(recv(&mut line).starts_with("TURN"));
+    writeln!(writer, "FOLD").unwrap();
+    assert_eq!(recv(&mut line), "ACTED 0 FOLD");
+
+    caller.join().unwrap();
+    assert_eq!(server.join().unwrap(), vec![995, 1005]);
+}
+
+#[test]
+fn test_unresponsive_client_is_folded() {
+    let (addr, server) = start(ServerConfig {
+        hands: 1,
+        timeout: Duration::from_millis(100),
+        ..ServerConfig::default()
+    });
+
+    let _sleeper = Client::connect(&addr, "sleeper").unwrap();
+    let caller = Client::connect(&addr, "caller").unwrap();
+    let caller = thread::spawn(move || caller.play(calling_station).unwrap());
+
+    assert_eq!(server.join().unwrap(), vec![995, 1005]);
+    caller.join().unwrap();
+}
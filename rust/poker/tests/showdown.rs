@@ -0,0 +1,109 @@
+#![cfg(feature = "std")]
+
+use poker::{evaluate, parse_cards, showdown, Card, HandRank, ShowdownError};
+
+fn hands(hands: &[&str]) -> Vec<Vec<Card>> {
+    hands
+        .iter()
+        .map(|hand| parse_cards(hand).unwrap())
+        .collect()
+}
+
+fn cards(cards: &str) -> Vec<Card> {
+    parse_cards(cards).unwrap()
+}
+
+#[test]
+fn test_single_winner() {
+    let result = showdown(&hands(&["4S 5S 7H 8D JC", "2S 4H 6S 4D JH"])).unwrap();
+    assert_eq!(result.winners, vec![1]);
+    assert_eq!(result.hands[0].category, HandRank::HighCard);
+    assert_eq!(result.hands[1].category, HandRank::OnePair);
+    assert_eq!(result.hands[1].description, "pair of fours");
+}
+
+#[test]
+fn test_identical_hands_keep_their_indices() {
+    let result = showdown(&hands(&[
+        "2S 2H 9D 9C KS",
+        "3S 4H 5D 8C JS",
+        "2S 2H 9D 9C KS",
+    ]))
+    .unwrap();
+    assert_eq!(result.winners, vec![0, 2]);
+}
+
+#[test]
+fn test_split_pot_with_different_suits() {
+    let result = showdown(&hands(&["4S 5H 6D 7C 8S", "4H 5D 6C 7S 8H"])).unwrap();
+    assert_eq!(result.winners, vec![0, 1]);
+}
+
+#[test]
+fn test_best_five_of_seven() {
+    let result = showdown(&hands(&["AS KS 2H 7D 9C QS JS", "AH AD 2H 7D 9C QS JS"])).unwrap();
+    assert_eq!(result.winners, vec![1]);
+    assert_eq!(result.hands[0].cards.to_vec(), cards("AS KS QS JS 9C"));
+    assert_eq!(result.hands[1].cards.to_vec(), cards("AH AD QS JS 9C"));
+}
+
+#[test]
+fn test_kickers() {
+    let result = showdown(&hands(&[
+        "AS AH KD 7C 2S",
+        "AS AH KD KC 2S",
+        "AS AH AD 7C 2S",
+        "AS AH AD KC KS",
+        "AS JH 8D 7C 2S",
+    ]))
+    .unwrap();
+    let kickers: Vec<Vec<Card>> = result
+        .hands
+        .iter()
+        .map(|hand| hand.kickers.clone())
+        .collect();
+    assert_eq!(
+        kickers,
+        vec![
+            cards("KD 7C 2S"),
+            cards("2S"),
+            cards("7C 2S"),
+            vec![],
+            cards("JH 8D 7C 2S"),
+        ]
+    );
+
+    let evaluation = evaluate(&cards("9S 9H 9D 9C 3S")).unwrap();
+    assert_eq!(evaluation.kickers(), &cards("3S")[..]);
+}
+
+#[test]
+fn test_showdown_needs_five_cards() {
+    assert_eq!(
+        showdown(&hands(&["AS AH KD 7C 2S", "AS AH"])),
+        Err(ShowdownError::NotEnoughCards { hand: 1, cards: 2 })
+    );
+}
+
+#[test]
+fn test_showdown_rejects_repeated_cards() {
+    let result = showdown(&hands(&["AS AH KD 7C 2S", "QS QS QH 7D 2C"]));
+    assert_eq!(
+        result,
+        Err(ShowdownError::DuplicateCard {
+            hand: 1,
+            card: "QS".parse().unwrap()
+        })
+    );
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "hand 1 has QS more than once"
+    );
+}
+
+#[test]
+fn test_no_hands() {
+    let result = showdown::<Vec<Card>>(&[]).unwrap();
+    assert!(result.winners.is_empty());
+    assert!(result.hands.is_empty());
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "std")]
+
+use poker::{HandDistribution, HandRank};
+
+fn table(distribution: &HandDistribution) -> Vec<(HandRank, u64, usize)> {
+    distribution
+        .categories()
+        .iter()
+        .map(|stats| (stats.category, stats.count, stats.distinct))
+        .collect()
+}
+
+#[test]
+fn test_five_card_distribution() {
+    let distribution = HandDistribution::five_card();
+    assert_eq!(distribution.total, 2_598_960);
+    assert_eq!(distribution.classes.len(), 7462);
+    assert_eq!(
+        table(&distribution),
+        vec![
+            (HandRank::StraightFlush, 40, 10),
+            (HandRank::FourOfAKind, 624, 156),
+            (HandRank::FullHouse, 3744, 156),
+            (HandRank::Flush, 5108, 1277),
+            (HandRank::Straight, 10200, 10),
+            (HandRank::ThreeOfAKind, 54912, 858),
+            (HandRank::TwoPair, 123552, 858),
+            (HandRank::OnePair, 1098240, 2860),
+            (HandRank::HighCard, 1302540, 1277),
+        ]
+    );
+
+    let probability: f64 = distribution
+        .categories()
+        .iter()
+        .map(|stats| stats.probability)
+        .sum();
+    assert!((probability - 1.0).abs() < 1e-9);
+
+    // The royal flush is the strongest class, and there are four of them.
+    let royal = distribution.classes[0];
+    assert_eq!((royal.category, royal.count), (HandRank::StraightFlush, 4));
+}
+
+#[test]
+fn test_distribution_csv() {
+    let csv = HandDistribution::five_card().to_csv();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines[0], "category,count,distinct,probability");
+    assert_eq!(lines[1], "straight flush,40,10,0.00001539");
+    assert_eq!(lines.len(), 10);
+}
+
+// Slow outside a release build:
+//
+//     cargo test --release --test stats -- --ignored
+#[test]
+#[ignore]
+fn test_seven_card_distribution() {
+    let distribution = HandDistribution::seven_card();
+    assert_eq!(distribution.total, 133_784_560);
+    assert_eq!(distribution.classes.len(), 4824);
+    assert_eq!(
+        table(&distribution),
+        vec![
+            (HandRank::StraightFlush, 41584, 10),
+            (HandRank::FourOfAKind, 224848, 156),
+            (HandRank::FullHouse, 3473184, 156),
+            (HandRank::Flush, 4047644, 1277),
+            (HandRank::Straight, 6180020, 10),
+            (HandRank::ThreeOfAKind, 6461620, 575),
+            (HandRank::TwoPair, 31433400, 763),
+            (HandRank::OnePair, 58627800, 1470),
+            (HandRank::HighCard, 23294460, 407),
+        ]
+    );
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "std")]
+
+use std::cmp::Ordering;
+
+use poker::{evaluate, parse_cards, showdown, winning_hand, Card, Suit, SuitOrder};
+
+fn cards(cards: &str) -> Vec<Card> {
+    parse_cards(cards).unwrap()
+}
+
+#[test]
+fn test_suit_order() {
+    let order = SuitOrder::default();
+    assert_eq!(order, SuitOrder::bridge());
+    assert_eq!(order.value(Suit::Spades), 3);
+    assert_eq!(order.value(Suit::Clubs), 0);
+    assert_eq!(SuitOrder::big_two().value(Suit::Diamonds), 0);
+
+    let order = [Suit::Hearts, Suit::Spades, Suit::Clubs, Suit::Diamonds];
+    assert_eq!(SuitOrder::new(order).unwrap().order(), order);
+    assert_eq!(
+        SuitOrder::new([Suit::Hearts, Suit::Hearts, Suit::Clubs, Suit::Diamonds]),
+        None
+    );
+}
+
+#[test]
+fn test_bring_in() {
+    // The lowest up card brings it in, the lowest suit breaking a tie.
+    let order = SuitOrder::bridge();
+    let up_cards = cards("2S 5H 2C 2D");
+    let bring_in = up_cards
+        .iter()
+        .copied()
+        .min_by(|&a, &b| order.compare_cards(a, b))
+        .unwrap();
+    assert_eq!(bring_in, cards("2C")[0]);
+    assert_eq!(
+        order.compare_cards(cards("3C")[0], cards("2S")[0]),
+        Ordering::Greater
+    );
+}
+
+#[test]
+fn test_winning_hand() {
+    let order = SuitOrder::bridge();
+
+    // The eight of spades beats the eight of hearts.
+    let straights = ["4H 5D 6C 7S 8H", "4S 5H 6D 7C 8S"];
+    assert_eq!(winning_hand(&straights, &order), Some(straights[1]));
+
+    // The best king of each pair decides it.
+    let pairs = ["KH KC 5S 4D 2C", "KS KD 5H 4C 2D"];
+    assert_eq!(winning_hand(&pairs, &order), Some(pairs[1]));
+
+    // Suits only break ties.
+    let hands = ["KS KD 5H 4C 2D", "3C 4C 5C 6C 8D 9D", "AC AD 3H 4S 5S"];
+    assert_eq!(winning_hand(&hands, &order), Some(hands[2]));
+
+    let flushes = ["AC KC 9C 5C 2C", "AD KD 9D 5D 2D"];
+    assert_eq!(winning_hand(&flushes, &order), Some(flushes[1]));
+    assert_eq!(
+        winning_hand(&flushes, &SuitOrder::big_two()),
+        Some(flushes[0])
+    );
+
+    assert_eq!(winning_hand(&[], &order), None);
+    assert_eq!(winning_hand(&["", "not a hand"], &order), Some(""));
+}
+
+#[test]
+fn test_compare_evaluations() {
+    let order = SuitOrder::bridge();
+    let spades = evaluate(&cards("AS AD 2H 2C 9S 7D 3C")).unwrap();
+    let hearts = evaluate(&cards("AH AC 2S 2D 9S 7D 3C")).unwrap();
+    assert_eq!(spades.strength, hearts.strength);
+    assert_eq!(order.compare(&spades, &hearts), Ordering::Greater);
+    assert_eq!(order.compare(&hearts, &hearts), Ordering::Equal);
+}
+
+#[test]
+fn test_showdown_winner_by_suit() {
+    let board = "QD JS 10C 4H 3H";
+    let hands: Vec<Vec<Card>> = ["AH KC", "AS KD", "2C 2D"]
+        .iter()
+        .map(|hole| cards(&format!("{hole} {board}")))
+        .collect();
+    let result = showdown(&hands).unwrap();
+    assert_eq!(result.winners, vec![0, 1]);
+    assert_eq!(result.winner_by_suit(&SuitOrder::bridge()), Some(1));
+
+    let hearts_first = SuitOrder::new([Suit::Hearts, Suit::Spades, Suit::Clubs, Suit::Diamonds]);
+    assert_eq!(result.winner_by_suit(&hearts_first.unwrap()), Some(0));
+}
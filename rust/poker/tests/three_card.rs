@@ -0,0 +1,94 @@
+#![cfg(feature = "std")]
+
+use poker::{
+    ante_house_edge, evaluate, evaluate_three_card, parse_cards, settle_ante, AnteBonus, HandRank,
+    PairPlus, ThreeCardHand, ThreeCardRank,
+};
+
+fn hand(cards: &str) -> ThreeCardHand {
+    let cards = parse_cards(cards).unwrap();
+    evaluate_three_card([cards[0], cards[1], cards[2]])
+}
+
+#[test]
+fn test_categories() {
+    assert_eq!(hand("AS KS QS").category, ThreeCardRank::StraightFlush);
+    assert_eq!(hand("7S 7H 7D").category, ThreeCardRank::ThreeOfAKind);
+    assert_eq!(hand("2S AH 3D").category, ThreeCardRank::Straight);
+    assert_eq!(hand("2S 9S JS").category, ThreeCardRank::Flush);
+    assert_eq!(hand("4S 4H JD").category, ThreeCardRank::Pair);
+    assert_eq!(hand("KS 3H JD").category, ThreeCardRank::HighCard);
+    assert_eq!(hand("KS AH 2D").category, ThreeCardRank::HighCard);
+}
+
+#[test]
+fn test_straight_beats_flush() {
+    assert!(hand("2S 3H 4D") > hand("AS KS JS"));
+    assert!(hand("7S 7H 7D") > hand("QS KH AD"));
+
+    // Unlike in five card poker.
+    let flush = evaluate(&parse_cards("AS KS JS 5S 3S").unwrap()).unwrap();
+    assert_eq!(flush.category, HandRank::Flush);
+    let straight = evaluate(&parse_cards("2S 3H 4D 5C 6S").unwrap()).unwrap();
+    assert!(flush > straight);
+}
+
+#[test]
+fn test_ordering_within_categories() {
+    assert!(hand("AS 2H 3D") < hand("2S 3H 4D"));
+    assert!(hand("QS KH AD") > hand("JS QH KD"));
+    assert!(hand("4S 4H AD") < hand("5S 5H 2D"));
+    assert!(hand("4S 4H AD") > hand("4D 4C KD"));
+    assert!(hand("AS 4H 2D") > hand("KS QH 9D"));
+    assert_eq!(hand("JS 9H 4D").strength, hand("JC 9D 4H").strength);
+}
+
+#[test]
+fn test_dealer_qualifies_with_queen_high() {
+    assert!(hand("QS 3H 2D").qualifies());
+    assert!(hand("2S 2H 3D").qualifies());
+    assert!(!hand("JS 10H 8D").qualifies());
+}
+
+#[test]
+fn test_settle_ante() {
+    let bonus = AnteBonus::default();
+    assert_eq!(settle_ante(&hand("KS KH 2D"), &hand("JS 9H 4D"), &bonus), 1);
+    assert_eq!(settle_ante(&hand("KS KH 2D"), &hand("QS 9H 4D"), &bonus), 2);
+    assert_eq!(
+        settle_ante(&hand("QS 9H 4D"), &hand("KS KH 2D"), &bonus),
+        -2
+    );
+    assert_eq!(settle_ante(&hand("QS 9H 4D"), &hand("QC 9D 4H"), &bonus), 0);
+    // The bonus is paid even when the dealer wins.
+    assert_eq!(settle_ante(&hand("2S 3H 4D"), &hand("2D 3C 4S"), &bonus), 1);
+    assert_eq!(settle_ante(&hand("7S 7H 7D"), &hand("AS AH AD"), &bonus), 2);
+}
+
+#[test]
+fn test_pair_plus_pays() {
+    let table = PairPlus::default();
+    assert_eq!(table.pays(&hand("AS KS QS")), 40);
+    assert_eq!(table.pays(&hand("4S 4H JD")), 1);
+    assert_eq!(table.pays(&hand("KS 3H JD")), -1);
+}
+
+#[test]
+fn test_pair_plus_house_edge() {
+    // 40/30/6/4/1 is the common table, with an edge of 2.32%.
+    let edge = PairPlus::default().house_edge();
+    assert!((edge - 0.0232).abs() < 0.0001, "{edge}");
+
+    let stingy = PairPlus {
+        flush: 3,
+        ..PairPlus::default()
+    };
+    assert!((stingy.house_edge() - 0.0728).abs() < 0.0001);
+}
+
+#[test]
+fn test_ante_house_edge() {
+    // The well known 3.37% for optimal play with a 5/4/1 ante bonus.
+    let edge = ante_house_edge(&AnteBonus::default());
+    assert!((edge - 0.0337).abs() < 0.0001, "{edge}");
+}
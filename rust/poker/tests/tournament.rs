@@ -0,0 +1,83 @@
+#![cfg(feature = "std")]
+
+use poker::{play_match, Action, Bot, CallingStation, GameState, MatchConfig, RuleBot};
+
+struct AlwaysFold;
+
+impl Bot for AlwaysFold {
+    fn act(&mut self, _: &GameState) -> Action {
+        Action::Fold
+    }
+}
+
+// Tries to check when facing a bet, which the harness turns into a fold.
+struct AlwaysCheck;
+
+impl Bot for AlwaysCheck {
+    fn act(&mut self, _: &GameState) -> Action {
+        Action::Check
+    }
+}
+
+struct MinRaiser;
+
+impl Bot for MinRaiser {
+    fn act(&mut self, state: &GameState) -> Action {
+        Action::Raise(state.min_raise_to)
+    }
+}
+
+fn config(deals: u64) -> MatchConfig {
+    MatchConfig {
+        deals,
+        ..MatchConfig::default()
+    }
+}
+
+#[test]
+fn test_identical_bots_break_even_under_duplicate_dealing() {
+    let result = play_match(&mut CallingStation, &mut CallingStation, &config(500)).unwrap();
+    assert_eq!(result.hands, 1000);
+    assert_eq!(result.chips, 0);
+    assert_eq!(result.bb_per_100, 0.0);
+    assert_eq!(result.ci95, 0.0);
+}
+
+#[test]
+fn test_folding_every_hand_loses_the_blinds() {
+    // Half a big blind on the button, and a whole one in the big blind
+    // once the calling station limps.
+    let result = play_match(&mut AlwaysFold, &mut CallingStation, &config(100)).unwrap();
+    assert_eq!(result.chips, -1500);
+    assert_eq!(result.bb_per_100, -75.0);
+    assert_eq!(result.ci95, 0.0);
+}
+
+#[test]
+fn test_illegal_actions_fall_back_to_folding() {
+    let result = play_match(&mut AlwaysCheck, &mut MinRaiser, &config(100)).unwrap();
+    assert_eq!(result.bb_per_100, -75.0);
+}
+
+#[test]
+fn test_seeded_matches_are_reproducible() {
+    let run = || {
+        let mut tag = RuleBot::new(true, true, 1);
+        let mut lag = RuleBot::new(false, true, 2);
+        play_match(&mut tag, &mut lag, &config(200)).unwrap()
+    };
+    let result = run();
+    assert_eq!(result, run());
+    assert!(result.ci95 > 0.0);
+}
+
+#[test]
+fn test_perspective_flips_with_the_bots() {
+    let mut tag = RuleBot::new(true, true, 1);
+    let mut station = CallingStation;
+    let forward = play_match(&mut tag, &mut station, &config(200)).unwrap();
+
+    let mut tag = RuleBot::new(true, true, 1);
+    let backward = play_match(&mut station, &mut tag, &config(200)).unwrap();
+    assert_eq!(forward.chips, -backward.chips);
+}